@@ -0,0 +1,205 @@
+use std::cell::Cell;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+use crate::days::Solution;
+use crate::{Answer, ParseError};
+
+thread_local! {
+    static PARSED: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+// Solvers call this once the input is parsed, splitting their time into parse and solve
+// phases. Solvers that parse as they go don't call it, and report no parse time.
+pub fn parsed() {
+    PARSED.with(|p| p.set(Some(Instant::now())));
+}
+
+// Time of one run of a solution
+#[derive(Debug, Clone, Copy)]
+pub struct Timing {
+    pub parse: Option<Duration>,
+    pub solve: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse.unwrap_or_default() + self.solve
+    }
+}
+
+// Run solution once, timing each phase
+pub fn time(solution: &Solution, input: &str) -> Result<(Answer, Timing), ParseError> {
+    PARSED.with(|p| p.set(None));
+    let start = Instant::now();
+    let answer = (solution.solve)(input)?;
+    let end = Instant::now();
+
+    let timing = match PARSED.with(Cell::take) {
+        Some(mark) => Timing {
+            parse: Some(mark - start),
+            solve: end - mark,
+        },
+        None => Timing {
+            parse: None,
+            solve: end - start,
+        },
+    };
+
+    Ok((answer, timing))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // None if there are no times
+    pub fn from_times(times: &[Duration]) -> Option<Self> {
+        let mut sorted = times.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        if n == 0 {
+            return None;
+        }
+        let median = if n % 2 == 1 {
+            sorted[n / 2]
+        } else {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[n - 1],
+        })
+    }
+}
+
+// Times of a solution over a number of iterations
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub solution: &'static Solution,
+    pub input: String,
+    pub answer: Answer,
+    pub iterations: usize,
+    pub parse: Option<Stats>,
+    pub solve: Stats,
+    pub total: Stats,
+}
+
+// Run solution 'iterations' times on input, read from 'path'
+pub fn bench(
+    solution: &'static Solution,
+    path: &str,
+    input: &str,
+    iterations: usize,
+) -> Result<Report, ParseError> {
+    let mut answer = Answer::Unsolved;
+    let mut timings = Vec::new();
+    for _ in 0..iterations.max(1) {
+        let (a, t) = time(solution, input).map_err(|e| e.in_file(path))?;
+        answer = a;
+        timings.push(t);
+    }
+
+    // Parse phase is only reported if every run marked it
+    let parse = timings.iter().map(|t| t.parse).collect::<Option<Vec<_>>>();
+    let solve = timings.iter().map(|t| t.solve).collect::<Vec<_>>();
+    let total = timings.iter().map(Timing::total).collect::<Vec<_>>();
+
+    Ok(Report {
+        solution,
+        input: path.to_string(),
+        answer,
+        iterations: timings.len(),
+        parse: parse.and_then(|p| Stats::from_times(&p)),
+        solve: Stats::from_times(&solve).unwrap(),
+        total: Stats::from_times(&total).unwrap(),
+    })
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut r = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(r, "\\u{:04x}", c as u32).unwrap(),
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+// Times are in milliseconds
+fn json_stats(stats: Option<Stats>) -> String {
+    let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+    match stats {
+        Some(s) => format!(
+            "{{\"min\": {:.6}, \"median\": {:.6}, \"max\": {:.6}}}",
+            ms(s.min),
+            ms(s.median),
+            ms(s.max)
+        ),
+        None => "null".to_string(),
+    }
+}
+
+// One object per report - unsolved answers and unmarked parse phases are null
+pub fn to_json(reports: &[Report]) -> String {
+    let mut r = String::from("[\n");
+    for (i, report) in reports.iter().enumerate() {
+        let answer = match report.answer {
+            Answer::Number(n) => n.to_string(),
+            Answer::Unsolved => "null".to_string(),
+        };
+        write!(
+            r,
+            "  {{\"solution\": {}, \"day\": {}, \"part\": {}, \"input\": {}, \"answer\": {}, \
+             \"iterations\": {}, \"parse_ms\": {}, \"solve_ms\": {}, \"total_ms\": {}}}",
+            json_string(report.solution.name),
+            report.solution.day,
+            report.solution.part,
+            json_string(&report.input),
+            answer,
+            report.iterations,
+            json_stats(report.parse),
+            json_stats(Some(report.solve)),
+            json_stats(Some(report.total)),
+        )
+        .unwrap();
+        r.push_str(if i + 1 < reports.len() { ",\n" } else { "\n" });
+    }
+    r.push_str("]\n");
+    r
+}
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+    assert_eq!(Stats::from_times(&[]), None);
+    let s = Stats::from_times(&[ms(5), ms(1), ms(3)]).unwrap();
+    assert_eq!((s.min, s.median, s.max), (ms(1), ms(3), ms(5)));
+    let s = Stats::from_times(&[ms(4), ms(1), ms(2), ms(8)]).unwrap();
+    assert_eq!((s.min, s.median, s.max), (ms(1), ms(3), ms(8)));
+}
+
+#[test]
+fn test_report() {
+    let solution = crate::days::find_by_name("day14_2").unwrap();
+    let input = std::fs::read_to_string("inputs/day14_test").unwrap();
+    let report = bench(solution, "inputs/day14_test", &input, 3).unwrap();
+    assert_eq!(report.answer, Answer::Number(64));
+    assert_eq!(report.iterations, 3);
+    assert!(report.parse.is_some());
+    assert!(report.total.min >= report.solve.min);
+
+    let json = to_json(&[report]);
+    assert!(json.starts_with("[\n  {\"solution\": \"day14_2\", \"day\": 14, \"part\": 2, \"input\": \"inputs/day14_test\", \"answer\": 64,"));
+    assert_eq!(json_string("a\"b\\\n"), "\"a\\\"b\\\\\\u000a\"");
+}
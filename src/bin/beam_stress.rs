@@ -0,0 +1,23 @@
+// Trace beams through a large generated day 16 layout, from the top few rows of the left edge -
+// many beams head straight back out, but the rest light up most of it
+//
+//   cargo run --release --bin beam_stress -- [width] [height] [spacing] [seed] [rows]
+use std::time::Instant;
+use aoc_2023::days::day16::{generate, Grid};
+
+fn main() {
+    let args: Vec<u64> = std::env::args().skip(1).map(|a| a.parse().expect("Bad number")).collect();
+    let arg = |i: usize, default| args.get(i).copied().unwrap_or(default);
+    let (width, height, spacing, seed) = (arg(0, 5000) as usize, arg(1, 5000) as usize, arg(2, 3) as u32, arg(3, 16));
+    let rows = arg(4, 10) as i32;
+
+    let start = Instant::now();
+    let mut grid = Grid::create(&generate(width, height, spacing, seed)).expect("Bad layout");
+    println!("{width}x{height} generated in {:?}", start.elapsed());
+
+    for y in 0..rows.min(height as i32) {
+        let start = Instant::now();
+        let energised = grid.trace_and_count(-1, y, 1, 0);
+        println!("Row {y:4}  Energised: {energised:9} in {:?}", start.elapsed());
+    }
+}
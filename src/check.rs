@@ -0,0 +1,131 @@
+use std::fs;
+use std::panic;
+use std::time::{Duration, Instant};
+
+use crate::days::{self, Solution};
+use crate::error::parse_lines;
+use crate::{Answer, ParseError};
+
+// Default location of recorded answers
+pub const MANIFEST_PATH: &str = "answers.txt";
+
+// One recorded answer - what a solution should give for an input
+#[derive(Debug, Clone)]
+pub struct Expected {
+    pub solution: &'static Solution,
+    pub input: String,
+    pub answer: Answer,
+}
+
+impl Expected {
+    // eg. "day14_2 inputs/day14 106390"
+    fn from_string(l: &str) -> Result<Self, ParseError> {
+        let fields = l.split_whitespace().collect::<Vec<_>>();
+        if fields.len() != 3 {
+            return Err(ParseError::new("Expected: <solution> <input> <answer>"));
+        }
+        let solution = days::find_by_name(fields[0]).ok_or_else(|| {
+            ParseError::new(format!("No solution named {}", fields[0])).within(l, fields[0])
+        })?;
+        let answer = fields[2]
+            .parse()
+            .map_err(|e: ParseError| e.within(l, fields[2]))?;
+
+        Ok(Expected {
+            solution,
+            input: fields[1].to_string(),
+            answer,
+        })
+    }
+}
+
+// One answer per line - blank lines and '#' comments are ignored
+pub fn parse_manifest(text: &str) -> Result<Vec<Expected>, ParseError> {
+    let entries = parse_lines(text, |l| {
+        let l = l.split('#').next().unwrap_or_default();
+        if l.trim().is_empty() {
+            Ok(None)
+        } else {
+            Expected::from_string(l).map(Some)
+        }
+    })?;
+
+    Ok(entries.into_iter().flatten().collect())
+}
+
+pub fn read_manifest(path: &str) -> Result<Vec<Expected>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("Cannot read {path}: {e}"))?;
+    parse_manifest(&text).map_err(|e| e.in_file(path).to_string())
+}
+
+// Result of running a solution against a recorded answer
+#[derive(Debug)]
+pub struct Outcome {
+    pub expected: Expected,
+    pub actual: Result<Answer, String>,
+    pub time: Duration,
+}
+
+impl Outcome {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected.answer)
+    }
+}
+
+// Run solution on its input - time covers just the solve, and panics are reported as failures
+pub fn check(expected: &Expected) -> Outcome {
+    let outcome = |actual, time| Outcome {
+        expected: expected.clone(),
+        actual,
+        time,
+    };
+
+    let input = match fs::read_to_string(&expected.input) {
+        Ok(input) => input,
+        Err(e) => {
+            let e = format!("Cannot read {}: {e}", expected.input);
+            return outcome(Err(e), Duration::ZERO);
+        }
+    };
+
+    let start = Instant::now();
+    let result = panic::catch_unwind(|| (expected.solution.solve)(&input));
+    let time = start.elapsed();
+
+    let actual = match result {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e)) => Err(e.in_file(&expected.input).to_string()),
+        Err(p) => {
+            let message = p
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| p.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(format!("panicked: {message}"))
+        }
+    };
+
+    outcome(actual, time)
+}
+
+#[test]
+fn test_manifest() {
+    let entries = parse_manifest("# comment\n\nday14_2 inputs/day14 106390 # trailing\n").unwrap();
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].solution.name, "day14_2");
+    assert_eq!(entries[0].input, "inputs/day14");
+    assert_eq!(entries[0].answer, Answer::Number(106390));
+
+    let e = parse_manifest("day14_2 inputs/day14 unsolved\nday99_1 inputs/day99 1\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+}
+
+#[test]
+fn test_answers() {
+    // Real inputs are too slow for a debug build - check those with: aoc --check
+    let entries = read_manifest(MANIFEST_PATH).unwrap();
+    for e in entries.iter().filter(|e| e.input.contains("_test")) {
+        let o = check(e);
+        assert!(o.passed(), "{} {}: expected {}, got {:?}", e.solution.name, e.input, e.answer, o.actual);
+    }
+}
@@ -0,0 +1,578 @@
+// Pulse circuits - flip-flops, conjunctions and broadcasters wired together, and a simulator
+// that pushes the button and delivers the pulses that follow
+use std::collections::{HashMap, VecDeque};
+use std::fmt::{self, Display, Formatter, Write};
+use std::str::FromStr;
+
+use crate::ParseError;
+use crate::bench::json_string;
+use crate::graph_export::{Graph, Shape};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Level {
+    Low,
+    High,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let s = if *self == Level::Low { "low" } else { "high" };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ModuleType {
+    FlipFlop,
+    Conjunction,
+    Broadcast,
+    // Only receives pulses - named as an output, but never defined
+    Output,
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    pub name: String,
+    pub typ: ModuleType,
+    // Module, and which of its inputs, that each output goes to
+    pub outputs: Vec<(usize, usize)>,
+    // Module that each input comes from
+    pub inputs: Vec<usize>,
+}
+
+// Source of pulses from the button
+pub const BUTTON: usize = usize::MAX;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Pulse {
+    pub level: Level,
+    pub src: usize,
+    pub dest: usize,
+    pub input: usize,
+}
+
+// Pulses to trace or break on - anything not given matches every pulse
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct PulseFilter {
+    pub src: Option<usize>,
+    pub dest: Option<usize>,
+    pub level: Option<Level>,
+}
+
+impl PulseFilter {
+    pub fn matches(&self, pulse: &Pulse) -> bool {
+        self.src.is_none_or(|s| s == pulse.src)
+            && self.dest.is_none_or(|d| d == pulse.dest)
+            && self.level.is_none_or(|l| l == pulse.level)
+    }
+
+    // eg. "dest=bq,level=high", "src=broadcaster" - an empty filter matches everything
+    pub fn parse(circuit: &Circuit, text: &str) -> Result<Self, ParseError> {
+        let mut filter = PulseFilter::default();
+        for term in text.split(',').filter(|t| !t.trim().is_empty()) {
+            let (key, value) = term.split_once('=').ok_or_else(|| {
+                ParseError::new("Expected: src=<module>, dest=<module> or level=<low|high>").within(text, term)
+            })?;
+            let module = || {
+                circuit.find(value.trim()).ok_or_else(|| ParseError::new(format!("Unknown module: {}", value.trim())).within(text, value))
+            };
+            match key.trim() {
+                "src" if value.trim() == "button" => filter.src = Some(BUTTON),
+                "src" => filter.src = Some(module()?),
+                "dest" => filter.dest = Some(module()?),
+                "level" => {
+                    filter.level = Some(match value.trim() {
+                        "low" => Level::Low,
+                        "high" => Level::High,
+                        _ => return Err(ParseError::new("Expected: low or high").within(text, value)),
+                    })
+                }
+                _ => return Err(ParseError::new(format!("Unknown filter: {}", key.trim())).within(text, key)),
+            }
+        }
+        Ok(filter)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TraceFormat {
+    Csv,
+    JsonLines,
+}
+
+impl FromStr for TraceFormat {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(TraceFormat::Csv),
+            "json" => Ok(TraceFormat::JsonLines),
+            _ => Err(ParseError::new(format!("Unknown trace format: {s} - expected csv or json"))),
+        }
+    }
+}
+
+// Pulses that match a filter, and the button press that each was part of - record() them from
+// a subscriber
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    pub filter: PulseFilter,
+    pub pulses: Vec<(usize, Pulse)>,
+}
+
+impl Trace {
+    pub fn new(filter: PulseFilter) -> Self {
+        Trace { filter, pulses: Vec::new() }
+    }
+
+    pub fn record(&mut self, press: usize, pulse: &Pulse) {
+        if self.filter.matches(pulse) {
+            self.pulses.push((press, *pulse));
+        }
+    }
+
+    pub fn render(&self, circuit: &Circuit, format: TraceFormat) -> String {
+        let mut s = String::new();
+        if format == TraceFormat::Csv {
+            s.push_str("press,src,dest,input,level\n");
+        }
+        for (press, p) in &self.pulses {
+            let (src, dest) = (circuit.name(p.src), circuit.name(p.dest));
+            match format {
+                TraceFormat::Csv => writeln!(s, "{press},{src},{dest},{},{}", p.input, p.level),
+                TraceFormat::JsonLines => writeln!(
+                    s,
+                    "{{\"press\": {press}, \"src\": {}, \"dest\": {}, \"input\": {}, \"level\": \"{}\"}}",
+                    json_string(src),
+                    json_string(dest),
+                    p.input,
+                    p.level
+                ),
+            }
+            .unwrap();
+        }
+        s
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Circuit {
+    modules: Vec<Module>,
+    names: HashMap<String, usize>,
+}
+
+impl Circuit {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Add a module, or give a type to one that so far has only been connected to
+    pub fn add_module(&mut self, name: &str, typ: ModuleType) -> usize {
+        if let Some(&n) = self.names.get(name) {
+            self.modules[n].typ = typ;
+            return n;
+        }
+        self.names.insert(name.to_string(), self.modules.len());
+        self.modules.push(Module {
+            name: name.to_string(),
+            typ,
+            outputs: Vec::new(),
+            inputs: Vec::new(),
+        });
+        self.modules.len() - 1
+    }
+
+    // Wire next output of 'from' to next input of 'to'
+    pub fn connect(&mut self, from: usize, to: usize) {
+        let input = self.modules[to].inputs.len();
+        self.modules[to].inputs.push(from);
+        self.modules[from].outputs.push((to, input));
+    }
+
+    // eg. "broadcaster -> a, b", "%a -> inv, con", "&con -> output"
+    //
+    // Modules are numbered in the order they are defined, then any outputs that are not
+    pub fn from_text(input: &str) -> Result<Self, ParseError> {
+        let mut circuit = Circuit::new();
+
+        // All definitions first, so that connections can refer forward to them
+        let mut connections = Vec::new();
+        for (i, l) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let (left, right) = l.split_once("->").ok_or(ParseError::at(i + 1, 0, "Expected: <module> -> <outputs>"))?;
+            let module = left.trim();
+            let (typ, name) = match module.chars().next() {
+                Some('%') => (ModuleType::FlipFlop, &module[1..]),
+                Some('&') => (ModuleType::Conjunction, &module[1..]),
+                _ if module == "broadcaster" => (ModuleType::Broadcast, module),
+                _ => return Err(ParseError::new(format!("Unknown module: {module}")).within(l, module).with_line(i + 1)),
+            };
+            if circuit.find(name).is_some() {
+                return Err(ParseError::new(format!("Module defined twice: {name}")).within(l, module).with_line(i + 1));
+            }
+            let n = circuit.add_module(name, typ);
+            connections.push((n, right, i, l, module));
+        }
+
+        for &(n, right, _, _, _) in &connections {
+            for output in right.split_terminator(',').map(str::trim) {
+                let o = circuit.find(output).unwrap_or_else(|| circuit.add_module(output, ModuleType::Output));
+                circuit.connect(n, o);
+            }
+        }
+
+        // Conjunctions remember each input as a bit in the simulator's memory
+        for &(n, _, i, l, module) in &connections {
+            let m = &circuit.modules[n];
+            if m.typ == ModuleType::Conjunction && m.inputs.len() > MAX_INPUTS {
+                let message = format!("Too many inputs to {}: {} - at most {MAX_INPUTS}", m.name, m.inputs.len());
+                return Err(ParseError::new(message).within(l, module).with_line(i + 1));
+            }
+        }
+
+        Ok(circuit)
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize> {
+        self.names.get(name).copied()
+    }
+
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    pub fn module(&self, n: usize) -> &Module {
+        &self.modules[n]
+    }
+
+    pub fn name(&self, n: usize) -> &str {
+        if n == BUTTON {
+            "button"
+        } else {
+            &self.modules[n].name
+        }
+    }
+
+    // Module n, and every module that feeds pulses into it, directly or not
+    pub fn feeding(&self, n: usize) -> Vec<usize> {
+        let mut seen = vec![false; self.modules.len()];
+        seen[n] = true;
+        let mut pending = vec![n];
+        let mut feeding = Vec::new();
+        while let Some(m) = pending.pop() {
+            feeding.push(m);
+            for &i in &self.modules[m].inputs {
+                if !seen[i] {
+                    seen[i] = true;
+                    pending.push(i);
+                }
+            }
+        }
+        feeding
+    }
+
+    // Modules shaped by type, and each connection labelled with the input it goes to
+    pub fn graph(&self) -> Graph {
+        let mut graph = Graph::new(true);
+        for m in &self.modules {
+            let shape = match m.typ {
+                ModuleType::FlipFlop => Shape::Box,
+                ModuleType::Conjunction => Shape::Diamond,
+                ModuleType::Broadcast => Shape::Circle,
+                ModuleType::Output => Shape::Ellipse,
+            };
+            graph.add_node(&m.name, shape);
+        }
+        for (n, m) in self.modules.iter().enumerate() {
+            for &(dest, input) in &m.outputs {
+                graph.add_edge(n, dest, Some(input.to_string()), false);
+            }
+        }
+        graph
+    }
+
+    // Where button pulses go
+    pub fn broadcaster(&self) -> Option<usize> {
+        self.modules.iter().position(|m| m.typ == ModuleType::Broadcast)
+    }
+}
+
+// Most inputs a conjunction can have - one bit of memory each
+const MAX_INPUTS: usize = 64;
+
+// Bit set for each input
+fn all_inputs(module: &Module) -> u64 {
+    match module.inputs.len() {
+        64 => u64::MAX,
+        n => (1 << n) - 1,
+    }
+}
+
+// Called with the button press number and each pulse as it arrives
+type Subscriber<'a> = Box<dyn FnMut(usize, &Pulse) + 'a>;
+
+pub struct Simulator<'a> {
+    circuit: &'a Circuit,
+    // Flip-flops are on if bit 0 is set, conjunctions have a bit set for each high input
+    memory: Vec<u64>,
+    pending: VecDeque<Pulse>,
+    presses: usize,
+    low_pulses: usize,
+    high_pulses: usize,
+    subscribers: Vec<Subscriber<'a>>,
+    breakpoints: Vec<PulseFilter>,
+}
+
+impl<'a> Simulator<'a> {
+    // Conjunctions must have at most MAX_INPUTS inputs - Circuit::from_text checks this
+    pub fn new(circuit: &'a Circuit) -> Self {
+        Simulator {
+            circuit,
+            memory: vec![0; circuit.modules.len()],
+            pending: VecDeque::new(),
+            presses: 0,
+            low_pulses: 0,
+            high_pulses: 0,
+            subscribers: Vec::new(),
+            breakpoints: Vec::new(),
+        }
+    }
+
+    // Everything off, and no pulses counted - subscribers stay
+    pub fn reset(&mut self) {
+        self.memory.fill(0);
+        self.pending.clear();
+        self.presses = 0;
+        self.low_pulses = 0;
+        self.high_pulses = 0;
+    }
+
+    pub fn subscribe(&mut self, subscriber: impl FnMut(usize, &Pulse) + 'a) {
+        self.subscribers.push(Box::new(subscriber));
+    }
+
+    // Stop delivering pulses after one that matches filter
+    pub fn break_on(&mut self, filter: PulseFilter) {
+        self.breakpoints.push(filter);
+    }
+
+    // Queue up a pulse, to be delivered by run()
+    pub fn inject(&mut self, pulse: Pulse) {
+        self.pending.push_back(pulse);
+    }
+
+    // Deliver pulses, in the order they were sent, until there are none left - or one matches a
+    // breakpoint, which is returned, and run() again carries on from there
+    pub fn run(&mut self) -> Option<Pulse> {
+        let circuit = self.circuit;
+        while let Some(pulse) = self.pending.pop_front() {
+            match pulse.level {
+                Level::Low => self.low_pulses += 1,
+                Level::High => self.high_pulses += 1,
+            }
+            for s in &mut self.subscribers {
+                s(self.presses, &pulse);
+            }
+
+            let n = pulse.dest;
+            let module = &circuit.modules[n];
+            let level = match module.typ {
+                ModuleType::Broadcast => Some(pulse.level),
+                ModuleType::FlipFlop if pulse.level == Level::High => None,
+                ModuleType::FlipFlop => {
+                    self.memory[n] ^= 1;
+                    Some(if self.memory[n] == 0 { Level::Low } else { Level::High })
+                }
+                ModuleType::Conjunction => {
+                    if pulse.level == Level::Low {
+                        self.memory[n] &= !(1 << pulse.input);
+                    } else {
+                        self.memory[n] |= 1 << pulse.input;
+                    }
+                    Some(self.output_level(n))
+                }
+                ModuleType::Output => None,
+            };
+
+            if let Some(level) = level {
+                for &(dest, input) in &module.outputs {
+                    self.pending.push_back(Pulse { level, src: n, dest, input });
+                }
+            }
+            if self.breakpoints.iter().any(|b| b.matches(&pulse)) {
+                return Some(pulse);
+            }
+        }
+        None
+    }
+
+    // Low pulse to broadcaster, then deliver everything that follows
+    pub fn press_button(&mut self) -> Option<Pulse> {
+        self.presses += 1;
+        if let Some(b) = self.circuit.broadcaster() {
+            self.inject(Pulse { level: Level::Low, src: BUTTON, dest: b, input: 0 });
+        }
+        self.run()
+    }
+
+    // Finish any press stopped at a breakpoint, then press until one is hit, or up to
+    // 'max_presses' in all - returns the press number and the pulse
+    pub fn press_until_break(&mut self, max_presses: usize) -> Option<(usize, Pulse)> {
+        if let Some(pulse) = self.run() {
+            return Some((self.presses, pulse));
+        }
+        while self.presses < max_presses {
+            if let Some(pulse) = self.press_button() {
+                return Some((self.presses, pulse));
+            }
+        }
+        None
+    }
+
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    // Low and high pulses delivered so far
+    pub fn pulse_counts(&self) -> (usize, usize) {
+        (self.low_pulses, self.high_pulses)
+    }
+
+    // Level a module last sent, or would send - flip-flops that are on, and conjunctions that
+    // don't remember every input as high, are high
+    pub fn output_level(&self, n: usize) -> Level {
+        let module = &self.circuit.modules[n];
+        let high = match module.typ {
+            ModuleType::FlipFlop => self.memory[n] != 0,
+            ModuleType::Conjunction => self.memory[n] != all_inputs(module),
+            ModuleType::Broadcast | ModuleType::Output => false,
+        };
+        if high { Level::High } else { Level::Low }
+    }
+
+    // Level that conjunction remembers for an input
+    pub fn remembered(&self, n: usize, input: usize) -> Level {
+        if self.memory[n] & (1 << input) != 0 { Level::High } else { Level::Low }
+    }
+}
+
+#[test]
+fn test_puzzle() {
+    for (input, expected) in [("inputs/day20_test", 32000000), ("inputs/day20_test2", 11687500)] {
+        let circuit = Circuit::from_text(&std::fs::read_to_string(input).unwrap()).unwrap();
+        let mut sim = Simulator::new(&circuit);
+        for _ in 0..1000 {
+            sim.press_button();
+        }
+        let (low, high) = sim.pulse_counts();
+        assert_eq!(low * high, expected, "{input}");
+    }
+
+    let circuit = Circuit::from_text(&std::fs::read_to_string("inputs/day20_test2").unwrap()).unwrap();
+    let output = circuit.find("output").unwrap();
+    assert_eq!(circuit.module(output).typ, ModuleType::Output);
+    assert_eq!(circuit.module(output).inputs, vec![circuit.find("con").unwrap()]);
+    let mut feeding = circuit.feeding(output);
+    feeding.sort();
+    assert_eq!(feeding, [0, 1, 2, 3, 4, 5]);
+    let mut feeding = circuit.feeding(circuit.find("b").unwrap());
+    feeding.sort();
+    assert_eq!(feeding, ["broadcaster", "a", "inv", "b"].map(|n| circuit.find(n).unwrap()));
+    let dot = circuit.graph().to_dot();
+    assert!(dot.contains("  \"con\" [shape=diamond];\n"));
+    assert!(dot.contains("  \"output\" [shape=ellipse];\n"));
+    assert!(dot.contains("  \"a\" -> \"con\" [label=\"0\"];\n"));
+    assert!(dot.contains("  \"b\" -> \"con\" [label=\"1\"];\n"));
+    assert!(Circuit::from_text("broadcaster -> a\n&a -> b\nb -> a").is_err());
+    assert!(Circuit::from_text("broadcaster -> a\n%a -> b\n%a -> b").is_err());
+
+    // Conjunction memory has a bit per input
+    let flip_flops = |n: usize| (0..n).map(|i| format!("%f{i} -> con\n")).collect::<String>();
+    assert!(Circuit::from_text(&format!("broadcaster -> f0\n&con -> out\n{}", flip_flops(64))).is_ok());
+    let e = Circuit::from_text(&format!("broadcaster -> f0\n&con -> out\n{}", flip_flops(65))).unwrap_err();
+    assert_eq!(e.to_string(), "2:1: Too many inputs to con: 65 - at most 64");
+}
+
+#[test]
+fn test_trace() {
+    let circuit = Circuit::from_text(&std::fs::read_to_string("inputs/day20_test2").unwrap()).unwrap();
+    let filter = |text| PulseFilter::parse(&circuit, text).unwrap();
+
+    let into_con = std::cell::RefCell::new(Trace::new(filter("dest=con, level=high")));
+    let everything = std::cell::RefCell::new(Trace::new(filter("")));
+    let mut sim = Simulator::new(&circuit);
+    sim.subscribe(|press, pulse| into_con.borrow_mut().record(press, pulse));
+    sim.subscribe(|press, pulse| everything.borrow_mut().record(press, pulse));
+
+    // Output gets its first high pulse on the first press, after 'con' has seen 'a' go high
+    sim.break_on(filter("dest=output,level=high"));
+    let (press, pulse) = sim.press_until_break(10).unwrap();
+    assert_eq!((press, circuit.name(pulse.src), pulse.level), (1, "con", Level::High));
+    assert_eq!(everything.borrow().pulses.len(), 6);
+
+    // Carry on with the rest of that press (b then sends con high), and break again in the next
+    assert!(sim.press_until_break(10).is_some());
+    assert_eq!((sim.presses(), everything.borrow().pulses.len()), (2, 14));
+    assert_eq!(into_con.borrow().pulses.len(), 2);
+    drop(sim);
+
+    let trace = into_con.into_inner();
+    assert_eq!(trace.render(&circuit, TraceFormat::Csv), "press,src,dest,input,level\n1,a,con,0,high\n1,b,con,1,high\n");
+    assert_eq!(
+        trace.render(&circuit, TraceFormat::JsonLines).lines().next(),
+        Some(r#"{"press": 1, "src": "a", "dest": "con", "input": 0, "level": "high"}"#)
+    );
+
+    assert_eq!(filter("src=button").src, Some(BUTTON));
+    assert_eq!(PulseFilter::parse(&circuit, "dest=con,level=up").unwrap_err().column, 16);
+    assert_eq!(PulseFilter::parse(&circuit, "dest=nope").unwrap_err().column, 6);
+    assert_eq!(PulseFilter::parse(&circuit, "dest").unwrap_err().column, 1);
+    assert_eq!("json".parse::<TraceFormat>(), Ok(TraceFormat::JsonLines));
+}
+
+#[test]
+fn test_simulator() {
+    // Flip-flop halving the button presses, into an inverter
+    let mut circuit = Circuit::new();
+    let b = circuit.add_module("broadcaster", ModuleType::Broadcast);
+    let inv = circuit.add_module("inv", ModuleType::Conjunction);
+    let ff = circuit.add_module("ff", ModuleType::FlipFlop);
+    circuit.connect(b, ff);
+    circuit.connect(ff, inv);
+    let out = circuit.add_module("out", ModuleType::Output);
+    circuit.connect(inv, out);
+
+    let received = std::cell::RefCell::new(Vec::new());
+    let mut sim = Simulator::new(&circuit);
+    sim.subscribe(|press, p| {
+        if p.dest == out {
+            received.borrow_mut().push((press, p.level));
+        }
+    });
+
+    sim.press_button();
+    assert_eq!(sim.output_level(ff), Level::High);
+    assert_eq!(sim.remembered(inv, 0), Level::High);
+    assert_eq!(sim.output_level(inv), Level::Low);
+    sim.press_button();
+    assert_eq!(sim.output_level(ff), Level::Low);
+    assert_eq!(sim.output_level(inv), Level::High);
+
+    // High pulses go straight through flip-flops
+    sim.inject(Pulse { level: Level::High, src: BUTTON, dest: ff, input: 0 });
+    sim.run();
+    assert_eq!(sim.output_level(ff), Level::Low);
+    sim.inject(Pulse { level: Level::Low, src: BUTTON, dest: ff, input: 0 });
+    sim.run();
+    assert_eq!(sim.output_level(ff), Level::High);
+    assert_eq!(sim.presses(), 2);
+    assert_eq!(sim.pulse_counts(), (8, 4));
+
+    sim.reset();
+    assert_eq!(sim.output_level(ff), Level::Low);
+    sim.press_button();
+    drop(sim);
+    assert_eq!(
+        received.into_inner(),
+        vec![(1, Level::Low), (2, Level::High), (2, Level::Low), (1, Level::Low)]
+    );
+}
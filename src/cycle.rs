@@ -0,0 +1,136 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+// Cycle in the sequence initial, step(initial), step(step(initial)), ...
+//
+// Returns (mu, lambda) - the index of the first state in the cycle, and the cycle length.
+// The sequence must cycle eventually, or this will not return.
+
+// Brent's algorithm - only keeps two states at a time
+pub fn detect_cycle<T: Clone + PartialEq>(initial: T, mut step: impl FnMut(&T) -> T) -> (usize, usize) {
+    // Find lambda - 'tortoise' waits at each power of two for 'hare' to come round to it
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone();
+            power *= 2;
+            lambda = 0;
+        }
+        hare = step(&hare);
+        lambda += 1;
+    }
+
+    // Find mu - with hare lambda steps ahead, they first meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..lambda {
+        hare = step(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+// Remember every state by hash - fewer steps than Brent's, but keeps every state so that
+// hash matches can be checked for equality
+pub fn detect_cycle_hashed<T: Hash + PartialEq>(initial: T, mut step: impl FnMut(&T) -> T) -> (usize, usize) {
+    let hash = |s: &T| {
+        let mut hasher = DefaultHasher::new();
+        s.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    let mut states = vec![initial];
+    loop {
+        let n = states.len() - 1;
+        let h = hash(&states[n]);
+        let matching = seen.entry(h).or_default();
+        if let Some(&mu) = matching.iter().find(|&&i| states[i] == states[n]) {
+            return (mu, n - mu);
+        }
+        matching.push(n);
+        let next = step(&states[n]);
+        states.push(next);
+    }
+}
+
+// Index of the earlier state that is the same as state 'n', given (mu, lambda) of sequence
+pub fn reduce_index(n: usize, (mu, lambda): (usize, usize)) -> usize {
+    if n < mu {
+        n
+    } else {
+        mu + (n - mu) % lambda
+    }
+}
+
+// State 'n' of the sequence, using the cycle to skip round it
+pub fn state_at<T>(initial: T, mut step: impl FnMut(&T) -> T, n: usize, cycle: (usize, usize)) -> T {
+    let mut state = initial;
+    for _ in 0..reduce_index(n, cycle) {
+        state = step(&state);
+    }
+    state
+}
+
+// State 'n' of the sequence - stepping until it gets there, or finds a cycle to skip round
+pub fn nth_state<T: Clone + Hash + Eq>(initial: T, mut step: impl FnMut(&T) -> T, n: usize) -> T {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    for i in 0..n {
+        if let Some(&mu) = seen.get(&state) {
+            return states.swap_remove(reduce_index(n, (mu, i - mu)));
+        }
+        seen.insert(state.clone(), i);
+        let next = step(&state);
+        states.push(state);
+        state = next;
+    }
+    state
+}
+
+#[test]
+fn test_cycle() {
+    // 0 1 2 3 4 5 6 7 8 3 4 ...
+    let step = |&n: &u32| if n == 8 { 3 } else { n + 1 };
+    assert_eq!(detect_cycle(0, step), (3, 6));
+    assert_eq!(detect_cycle_hashed(0, step), (3, 6));
+    assert_eq!(state_at(0, step, 2, (3, 6)), 2);
+    assert_eq!(state_at(0, step, 1000, (3, 6)), 4);
+    assert_eq!(nth_state(0, step, 2), 2);
+    assert_eq!(nth_state(0, step, 1000), 4);
+
+    // Whole sequence is a cycle
+    let step = |&n: &u32| (n + 1) % 5;
+    assert_eq!(detect_cycle(0, step), (0, 5));
+    assert_eq!(detect_cycle_hashed(0, step), (0, 5));
+
+    // Fixed point
+    assert_eq!(detect_cycle(7, |&n: &u32| n), (0, 1));
+    assert_eq!(detect_cycle_hashed(7, |&n: &u32| n), (0, 1));
+}
+
+#[test]
+fn test_hash_collision() {
+    // Every state hashes the same, so only equality can tell them apart
+    #[derive(Clone, PartialEq)]
+    struct Colliding(u32);
+    impl Hash for Colliding {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            0.hash(state)
+        }
+    }
+
+    let step = |c: &Colliding| Colliding((c.0 * 3 + 1) % 10);
+    assert_eq!(detect_cycle_hashed(Colliding(0), step), detect_cycle(Colliding(0), step));
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::grid::{Cell, Grid};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Tile {
@@ -12,26 +13,33 @@ enum Tile {
 	Start =7,
 }
 
-fn tile_from_char(c: char) -> Tile {
-	match c {
-		'|' => Tile::Vertical,
-		'-' => Tile::Horizontal,
-		'L' => Tile::CornerNE,
-		'J' => Tile::CornerNW,
-		'7' => Tile::CornerSW,
-		'F' => Tile::CornerSE,
-		'S' => Tile::Start,
-		_ => Tile::Ground,
+impl Cell for Tile {
+	fn from_char(c: char) -> Option<Self> {
+		Some(match c {
+			'|' => Tile::Vertical,
+			'-' => Tile::Horizontal,
+			'L' => Tile::CornerNE,
+			'J' => Tile::CornerNW,
+			'7' => Tile::CornerSW,
+			'F' => Tile::CornerSE,
+			'S' => Tile::Start,
+			'.' => Tile::Ground,
+			_ => return None,
+		})
 	}
-}
 
-fn find_value<T: PartialEq>(arr: &[T], target: &T) -> Option<usize> {
-    for (index, item) in arr.iter().enumerate() {
-        if item == target {
-            return Some(index);
-        }
-    }
-    None
+	fn to_char(&self) -> char {
+		match self {
+			Tile::Vertical => '|',
+			Tile::Horizontal => '-',
+			Tile::CornerNE => 'L',
+			Tile::CornerNW => 'J',
+			Tile::CornerSW => '7',
+			Tile::CornerSE => 'F',
+			Tile::Start => 'S',
+			Tile::Ground => '.',
+		}
+	}
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -47,34 +55,19 @@ impl Coord {
 
 #[derive(Debug, Clone)]
 struct Map {
-	width: usize,
-	height: usize,
-	tiles: Vec<Tile>,
+	tiles: Grid<Tile>,
 	start: Coord,
 }
 
 impl Map {
-	fn from_text(text: &str) -> Map {
-
-		// Firure width and height
-		let (mut width, mut height) = (0,0);
-		let mut tiles = vec![];
-
-		for l in text.lines() {
-			width = usize::max(width, l.len());
-			height += 1;
-			tiles.extend(l.chars().map(tile_from_char));
-		}
-		let start_offset = find_value(&tiles, &Tile::Start).unwrap();
-		Map {width, height, tiles, start: Coord::at((start_offset % width) as i32 , (start_offset / width) as i32)  }
+	fn from_text(text: &str) -> Result<Map, ParseError> {
+		let tiles = Grid::from_text(text)?;
+		let (x, y) = tiles.position(|&t| t == Tile::Start).ok_or(ParseError::new("No start tile"))?;
+		Ok(Map { tiles, start: Coord::at(x, y) })
 	}
 
 	fn at(&self, p: Coord) -> Tile {
-		if p.x < 0 || p.y < 0 || p.x >= self.width as i32 || p.y > self.height as i32 {
-			Tile::Ground
-		} else {
-			self.tiles[p.y as usize * self.width + p.x as usize]
-		}
+		self.tiles.get_or(p.x, p.y, Tile::Ground)
 	}
 
 	fn neighbours(&self, p: Coord) -> Option<(Coord, Coord)> {
@@ -109,12 +102,11 @@ impl Map {
 
 }
 
-fn main()
+pub fn solve(input: &str) -> Result<Answer, ParseError>
 {
-	println!("Hello Day 10 1!");
-
-	let map = Map::from_text(&fs::read_to_string("inputs/day10").unwrap());
-	println!("Start: {:?}", map.start);
+	let map = Map::from_text(input)?;
+	bench::parsed();
+//	println!("Start: {:?}", map.start);
 
 	// Filter the four neighbours of start tile by connection
 	// Shoule end up with two connected neighbours
@@ -126,16 +118,19 @@ fn main()
 	    	.into_iter()
 	    	.filter(|&c| map.is_connected(map.start, c))
 	    	.collect::<Vec<_>>();
-	assert_eq!(start_neigbours.len(), 2);
+	if start_neigbours.len() != 2 {
+		let message = format!("Start connects to {} pipes, not 2", start_neigbours.len());
+		return Err(ParseError::at(map.start.y as usize + 1, map.start.x as usize + 1, message));
+	}
 
    	let mut from = (map.start, map.start);
    	let mut current = (start_neigbours[0], start_neigbours[1]);
    	let mut steps = 1;
-   	let mut trail = vec!['.'; map.width * map.height];
+   	let mut trail = vec!['.'; map.tiles.width * map.tiles.height];
    	loop {
 
-	   	trail[map.width * current.0.y as usize + current.0.x as usize] = char::from_digit(steps % 16,16).unwrap();
-	   	trail[map.width * current.1.y as usize + current.1.x as usize] = char::from_digit(steps % 16,16).unwrap();
+	   	trail[map.tiles.width * current.0.y as usize + current.0.x as usize] = char::from_digit(steps % 16,16).unwrap();
+	   	trail[map.tiles.width * current.1.y as usize + current.1.x as usize] = char::from_digit(steps % 16,16).unwrap();
 
    		if let Some(c) = map.next(current.0, from.0) {
    			from.0 = current.0;
@@ -159,10 +154,16 @@ fn main()
 		}
    	}
 
-   	for y in 0..map.height {
-   		let line = trail[(y * map.width as usize)..((y+1) * map.width as usize)].iter().collect::<String>();
-   		println!("{}", line);
-   	}
-   	println!("Steps: {steps}")
+//   	for y in 0..map.tiles.height {
+//   		let line = trail[(y * map.tiles.width)..((y+1) * map.tiles.width)].iter().collect::<String>();
+//   		println!("{}", line);
+//   	}
+   	Ok(steps.into())
    	// 6909
 }
+
+#[test]
+fn test_start() {
+	let e = solve("...\n.S.\n...").unwrap_err();
+	assert_eq!((e.line, e.column, e.message.as_str()), (2, 2, "Start connects to 0 pipes, not 2"));
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
 
 #[derive(Debug, Clone, Copy)]
 struct Coord {
@@ -15,7 +15,7 @@ struct Image {
 }
 
 impl Image {
-	fn from_text(text: &str) -> Image {
+	fn from_text(text: &str) -> Result<Image, ParseError> {
 
 		// Figure width and height
 		let (mut width, mut height) = (0,0);
@@ -23,8 +23,15 @@ impl Image {
 		let mut row_flags = vec![];
 		let mut column_flags = vec![];
 
-		for l in text.lines() {
-			width = usize::max(width, l.len());
+		for (y, l) in text.lines().enumerate() {
+			if let Some(x) = l.find(|c| c != '.' && c != '#') {
+				return Err(ParseError::at(y + 1, x + 1, format!("Bad character {:?}", l[x..].chars().next().unwrap())));
+			}
+			if y == 0 {
+				width = l.len();
+			} else if l.len() != width {
+				return Err(ParseError::at(y + 1, l.len().min(width) + 1, format!("Line length is not {width}")));
+			}
 			height += 1;
 			let lb = l.chars().map(|c| c == '#').collect::<Vec<_>>();
 			column_flags.resize(width, true);
@@ -55,7 +62,7 @@ impl Image {
 				}
 			}
 		}
-		Image {width: new_width, height: new_height, pixels: new_pixels, galaxies: galaxies}
+		Ok(Image {width: new_width, height: new_height, pixels: new_pixels, galaxies})
 	}
 
 	fn at(&self, x: i32, y: i32) -> u32 {
@@ -83,13 +90,10 @@ impl Image {
 	}
 }
 
-fn main() {
-	println!("Hello Day 11 1!");
-
-	let input = fs::read_to_string("inputs/day11").unwrap();
-
-	let image = Image::from_text(&input);
-	image.dump();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let image = Image::from_text(input)?;
+	bench::parsed();
+//	image.dump();
 
 	let mut sum = 0;
 	for ga in 1 .. image.galaxies.len() {
@@ -102,6 +106,11 @@ fn main() {
 		}
 	}
 
-	println!("Sum: {sum}")
+	Ok(sum.into())
 	// 10490062
-}
\ No newline at end of file
+}
+#[test]
+fn test_line_length() {
+	let e = Image::from_text("#..\n.#").unwrap_err();
+	assert_eq!((e.line, e.column, e.message.as_str()), (2, 3, "Line length is not 3"));
+}
@@ -1,4 +1,4 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
 
 #[derive(Debug, Clone, Copy)]
 struct Coord {
@@ -13,7 +13,7 @@ struct Image {
 
 const EXPANSION: i64 = 999999;
 impl Image {
-	fn from_text(text: &str) -> Image {
+	fn from_text(text: &str) -> Result<Image, ParseError> {
 
 		// Figure width and height
 		let (mut width, mut height) = (0,0);
@@ -21,8 +21,15 @@ impl Image {
 		let mut row_flags = vec![];
 		let mut column_flags = vec![];
 
-		for l in text.lines() {
-			width = usize::max(width, l.len());
+		for (y, l) in text.lines().enumerate() {
+			if let Some(x) = l.find(|c| c != '.' && c != '#') {
+				return Err(ParseError::at(y + 1, x + 1, format!("Bad character {:?}", l[x..].chars().next().unwrap())));
+			}
+			if y == 0 {
+				width = l.len();
+			} else if l.len() != width {
+				return Err(ParseError::at(y + 1, l.len().min(width) + 1, format!("Line length is not {width}")));
+			}
 			height += 1;
 			let lb = l.chars().map(|c| c == '#').collect::<Vec<_>>();
 			column_flags.resize(width, true);
@@ -47,16 +54,13 @@ impl Image {
 				}
 			}
 		}
-		Image { galaxies: galaxies }
+		Ok(Image { galaxies })
 	}
 }
 
-fn main() {
-	println!("Hello Day 11 1!");
-
-	let input = fs::read_to_string("inputs/day11").unwrap();
-
-	let image = Image::from_text(&input);
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let image = Image::from_text(input)?;
+	bench::parsed();
 
 	let mut sum = 0;
 	for ga in 1 .. image.galaxies.len() {
@@ -68,6 +72,6 @@ fn main() {
 		}
 	}
 
-	println!("Sum: {sum}")
+	Ok(sum.into())
 	// 382979724122
 }
\ No newline at end of file
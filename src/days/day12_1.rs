@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, Copy, Clone,PartialEq)]
 enum Condition {
@@ -8,11 +9,12 @@ enum Condition {
 }
 
 impl Condition {
-	fn from_char(c: char) -> Self {
+	fn from_char(c: char) -> Option<Self> {
 		match c {
-		'?' => Condition::Unknown,
-		'#' => Condition::Spring,
-		_ => Condition::None
+		'?' => Some(Condition::Unknown),
+		'#' => Some(Condition::Spring),
+		'.' => Some(Condition::None),
+		_ => None
 		}
 	}
 }
@@ -56,26 +58,37 @@ fn fill_unknowns(springs: &Vec<Condition>, pattern:u32) -> Vec<Condition> {
 	r
 }
 
+// eg. "???.### 1,1,3"
+fn parse_record(text: &str) -> Result<(Vec<Condition>, Vec<u32>), ParseError>
+{
+	let Some((springs_text, groups_text)) = text.split_once(' ') else {
+		return Err(ParseError::new("Expected: <springs> <groups>"));
+	};
+	let springs = springs_text.chars().enumerate().map(|(i, c)| {
+		Condition::from_char(c).ok_or_else(|| ParseError::new(format!("Bad spring: {c}")).with_column(i + 1))
+	}).collect::<Result<Vec<_>, _>>()?;
+	let groups = groups_text.split(',').map(|s| parse_value::<u32>(s).map_err(|e| e.within(text, s))).collect::<Result<Vec<_>, _>>()?;
+	Ok((springs, groups))
+}
 
-fn main()
+pub fn solve(input: &str) -> Result<Answer, ParseError>
 {
-	println!("Hello Day 12 1!");
+	let records = parse_lines(input, parse_record)?;
+	bench::parsed();
 
-	let input = fs::read_to_string("inputs/day12").unwrap();
 	let mut sum = 0;
-	for l in input.lines() {
-		let line_parts = l.split_whitespace().collect::<Vec<_>>();
-		let (springs,groups) = (
-			line_parts[0].chars().map(Condition::from_char).collect::<Vec<_>>(),
-			line_parts[1].split(',').map(|s| s.parse::<u32>().unwrap()).collect::<Vec<_>>());
-		println!("s:{springs:?} g:{groups:?}");
+	for (springs,groups) in records {
+//		println!("s:{springs:?} g:{groups:?}");
 
 		let num_unknowns = springs.iter().filter(|c| **c == Condition::Unknown).count();
 		let num_springs = springs.iter().filter(|c| **c == Condition::Spring).count();
 		let total_springs:u32 = groups.iter().sum();
-		let unknown_springs = total_springs - num_springs as u32;
+		// More springs than groups need - no arrangements
+		let Some(unknown_springs) = total_springs.checked_sub(num_springs as u32) else {
+			continue;
+		};
 
-		println!("Nu: {num_unknowns}");
+//		println!("Nu: {num_unknowns}");
 
 		// for possible combination of umknowns
 		//  filter those with correct number of unknown springs
@@ -89,10 +102,10 @@ fn main()
 			}
 		}
 
-		println!("Arragments: {arrangements}");
+//		println!("Arragments: {arrangements}");
 		sum += arrangements;
 	}
 
-	println!("Sum: {sum}");
+	Ok(sum.into())
 	// 7771
 }
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
-use std::fs;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Condition {
@@ -9,11 +11,12 @@ enum Condition {
 }
 
 impl Condition {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '?' => Condition::U,
-            '#' => Condition::S,
-            _ => Condition::E,
+            '?' => Some(Condition::U),
+            '#' => Some(Condition::S),
+            '.' => Some(Condition::E),
+            _ => None,
         }
     }
 }
@@ -26,21 +29,45 @@ struct Record {
 }
 
 impl Record {
-    fn create(springs_text: &str, groups_text: &str) -> Self {
+    // eg. "???.### 1,1,3"
+    fn create(text: &str) -> Result<Self, ParseError> {
+        let Some((springs_text, groups_text)) = text.split_once(' ') else {
+            return Err(ParseError::new("Expected: <springs> <groups>"));
+        };
         let (springs, groups) = (
             springs_text
                 .chars()
-                .map(Condition::from_char)
-                .collect::<Vec<_>>(),
+                .enumerate()
+                .map(|(i, c)| {
+                    Condition::from_char(c)
+                        .ok_or_else(|| ParseError::new(format!("Bad spring: {c}")).with_column(i + 1).within(text, springs_text))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
             groups_text
                 .split(',')
-                .map(|s| s.parse::<u32>().unwrap())
-                .collect::<Vec<_>>(),
+                .map(|s| parse_value::<u32>(s).map_err(|e| e.within(text, s)))
+                .collect::<Result<Vec<_>, _>>()?,
         );
-        Record {
+        Ok(Record {
             springs,
             groups,
             memo: HashMap::new(),
+        })
+    }
+
+    // Repeat springs n times, separated by unknowns, and groups n times
+    fn unfold(&self, n: usize) -> Self {
+        let mut springs = Vec::new();
+        for i in 0..n {
+            if i > 0 {
+                springs.push(Condition::U);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Record {
+            springs,
+            groups: self.groups.repeat(n),
+            memo: HashMap::new(),
         }
     }
 
@@ -117,29 +144,28 @@ impl Record {
     }
 }
 
-fn main() {
-    println!("Hello Day 12 2!");
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    assert_eq!(Record::create("# 1").unwrap().arrangements(), 1);
 
-    assert_eq!(Record::create("#", "1").arrangements(), 1);
+    let records = parse_lines(input, Record::create)?;
+    bench::parsed();
 
-    let input = fs::read_to_string("inputs/day12").unwrap();
     let mut sum = 0;
-    for l in input.lines() {
-        let split = l.split_whitespace().collect::<Vec<_>>();
-        let mut r = Record::create(&[split[0]; 5].join("?"), &[split[1]; 5].join(","));
+    for record in records {
+        let mut r = record.unfold(5);
         let arrangements = r.arrangements();
 
-        println!("Arragments:  {arrangements}");
-
         sum += arrangements;
     }
 
-    println!("Sum: {sum}");
+    Ok(sum.into())
     // 10861030975833
 }
 
 #[test]
 fn test_1() {
-    assert_eq!(Record::create("? 0").arrangements(), 1);
-    assert_eq!(Record::create(". 0").arrangements(), 1);
+    assert_eq!(Record::create("? 0").unwrap().arrangements(), 1);
+    assert_eq!(Record::create(". 0").unwrap().arrangements(), 1);
+    assert_eq!(Record::create("?# 1").unwrap().unfold(2).springs, Record::create("?#??# 1").unwrap().springs);
+    assert_eq!(Record::create("#x 1").unwrap_err().column, 2);
 }
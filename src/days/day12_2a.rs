@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, Copy, Clone, PartialEq)]
 enum Condition {
@@ -8,11 +9,12 @@ enum Condition {
 }
 
 impl Condition {
-    fn from_char(c: char) -> Self {
+    fn from_char(c: char) -> Option<Self> {
         match c {
-            '?' => Condition::U,
-            '#' => Condition::S,
-            _ => Condition::E,
+            '?' => Some(Condition::U),
+            '#' => Some(Condition::S),
+            '.' => Some(Condition::E),
+            _ => None,
         }
     }
 }
@@ -67,19 +69,26 @@ struct Record {
 }
 
 impl Record {
-	fn create(text: &str) -> Self {
-        let line_parts = text.split_whitespace().collect::<Vec<_>>();
+	// eg. "???.### 1,1,3"
+	fn create(text: &str) -> Result<Self, ParseError> {
+        let Some((springs_text, groups_text)) = text.split_once(' ') else {
+            return Err(ParseError::new("Expected: <springs> <groups>"));
+        };
         let (springs, groups) = (
-            line_parts[0]
+            springs_text
                 .chars()
-                .map(Condition::from_char)
-                .collect::<Vec<_>>(),
-            line_parts[1]
+                .enumerate()
+                .map(|(i, c)| {
+                    Condition::from_char(c)
+                        .ok_or_else(|| ParseError::new(format!("Bad spring: {c}")).with_column(i + 1))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            groups_text
                 .split(',')
-                .map(|s| s.parse::<u32>().unwrap())
-                .collect::<Vec<_>>(),
+                .map(|s| parse_value::<u32>(s).map_err(|e| e.within(text, s)))
+                .collect::<Result<Vec<_>, _>>()?,
         );
-        Record {springs, groups}
+        Ok(Record {springs, groups})
 	}
 }
 
@@ -135,27 +144,17 @@ fn arrangements_group_end(springs: &[Condition], group: u32, next_groups: &[u32]
 }
 
 
-fn main() {
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
 	use Condition::*;
-    println!("Hello Day 12 2!");
 
 	assert_eq!(arrangements(&vec![S,E], 1, &vec![]), 1);
 
-    let input = fs::read_to_string("inputs/day12").unwrap();
+    let records = parse_lines(input, Record::create)?;
+    bench::parsed();
+
     let mut enum_sum = 0;
     let mut rec_sum = 0;
-    for l in input.lines() {
-        let line_parts = l.split_whitespace().collect::<Vec<_>>();
-        let (springs, groups) = (
-            line_parts[0]
-                .chars()
-                .map(Condition::from_char)
-                .collect::<Vec<_>>(),
-            line_parts[1]
-                .split(',')
-                .map(|s| s.parse::<u32>().unwrap())
-                .collect::<Vec<_>>(),
-        );
+    for Record { springs, groups } in records {
 
         let num_unknowns = springs.iter().filter(|c| **c == Condition::U).count();
         let num_springs = springs.iter().filter(|c| **c == Condition::S).count();
@@ -175,13 +174,14 @@ fn main() {
         }
         let mut rec_arrangements = arrangements(&springs, groups[0], &groups[1..]);
 
-        println!("Arragments: {enum_arrangements} {rec_arrangements}");
+//        println!("Arragments: {enum_arrangements} {rec_arrangements}");
 
         enum_sum += enum_arrangements;
         rec_sum += rec_arrangements;
     }
 
-    println!("Sum: {enum_sum} {rec_sum}");
+    assert_eq!(enum_sum, rec_sum);
+    Ok(rec_sum.into())
 }
 
 #[test]
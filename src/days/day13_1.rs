@@ -0,0 +1,91 @@
+use crate::{bench, Answer, ParseError};
+use crate::grid::Grid;
+
+#[derive(Debug)]
+struct Pattern {
+    pixels: Grid<bool>,
+}
+
+impl Pattern {
+    fn create(text_lines: &[String]) -> Result<Self, ParseError> {
+        Ok(Pattern {
+            pixels: Grid::from_text(&text_lines.join("\n"))?,
+        })
+    }
+
+    fn dump(&self) {
+        println!("-- {} x {}", self.pixels.width, self.pixels.height);
+        print!("{}", self.pixels);
+    }
+
+    fn rows(&self) -> Vec<Vec<bool>> {
+        self.pixels.rows().map(<[bool]>::to_vec).collect()
+    }
+
+    fn columns(&self) -> Vec<Vec<bool>> {
+        self.pixels.columns().collect()
+    }
+}
+
+fn find_reflection(lines: &[Vec<bool>]) -> Option<usize> {
+	// For each possible split ...
+	let mut output = 0;
+	let mut count = 0;
+	for refl in 1..lines.len() {
+		let distance = usize::min(refl, lines.len()-refl);
+		let b = (0..distance).map(|i| lines[refl-i-1] == lines[refl+i]).all(|b| b);
+//		println!("  refl:{refl} dist:{distance} b:{b}");
+		if b {
+			output =refl;
+			count += 1;
+		}
+	}
+
+	if count > 1 {
+		println!("More than 1 match: {}", count);
+	}
+
+	if count > 0 {
+		Some(output)
+	} else {
+		None
+	}
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut lines = input.lines();
+    let mut patterns = Vec::new();
+    let mut first_line = 1;
+
+    loop {
+        let pattern_lines = lines
+            .by_ref()
+            .take_while(|l| !l.is_empty())
+            .map(str::to_string).collect::<Vec<_>>();
+        if pattern_lines.is_empty() {
+        	break;
+        }
+        // Errors are reported relative to start of pattern
+        let pattern = Pattern::create(&pattern_lines)
+            .map_err(|e| ParseError { line: e.line + first_line - 1, ..e })?;
+        first_line += pattern_lines.len() + 1;
+        patterns.push(pattern);
+    }
+    bench::parsed();
+
+    let mut sum =0;
+    for p in &patterns {
+//		p.dump();
+		let vreflect = find_reflection(&p.columns());
+		let hreflect = find_reflection(&p.rows());
+//		println!("h: {hreflect:?} v:{vreflect:?}");
+		if let Some(r) = vreflect {
+			sum += r;
+		}
+		if let Some(r) = hreflect {
+			sum += r * 100;
+		}
+    }
+
+    Ok(sum.into())
+}
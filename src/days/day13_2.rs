@@ -0,0 +1,117 @@
+use std::iter::zip;
+
+use crate::{bench, Answer, ParseError};
+use crate::grid::Grid;
+
+
+#[derive(Debug)]
+struct Pattern {
+    pixels: Grid<bool>,
+}
+
+impl Pattern {
+    fn create(text_lines: &[String]) -> Result<Self, ParseError> {
+        Ok(Pattern {
+            pixels: Grid::from_text(&text_lines.join("\n"))?,
+        })
+    }
+
+    fn dump(&self) {
+        println!("-- {} x {}", self.pixels.width, self.pixels.height);
+        print!("{}", self.pixels);
+    }
+
+    fn rows(&self) -> Vec<Vec<bool>> {
+        self.pixels.rows().map(<[bool]>::to_vec).collect()
+    }
+
+    fn columns(&self) -> Vec<Vec<bool>> {
+        self.pixels.columns().collect()
+    }
+}
+
+fn bit_errors(a: &[bool], b: &[bool]) -> usize {
+    zip(a, b).map(|(a, b)| if a != b { 1 } else { 0 }).sum()
+}
+
+fn find_reflection(lines: &[Vec<bool>], smudges: usize) -> Option<usize> {
+    // For each possible split ...
+    for refl in 1..lines.len() {
+        let distance = usize::min(refl, lines.len() - refl);
+        // Figure total bit errors over reflection
+        let total_bit_errors: usize = (0..distance)
+            .map(|i| bit_errors(&lines[refl - i - 1], &lines[refl + i]))
+            .sum();
+        if total_bit_errors == smudges {
+            return Some(refl);
+        }
+    }
+
+    None
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut lines = input.lines();
+    let mut patterns = Vec::new();
+    let mut first_line = 1;
+
+    loop {
+        let pattern_lines = lines
+            .by_ref()
+            .take_while(|l| !l.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if pattern_lines.is_empty() {
+            break;
+        }
+        // Errors are reported relative to start of pattern
+        let pattern = Pattern::create(&pattern_lines)
+            .map_err(|e| ParseError { line: e.line + first_line - 1, ..e })?;
+        first_line += pattern_lines.len() + 1;
+        patterns.push(pattern);
+    }
+    bench::parsed();
+
+    let mut sum = 0;
+    let smudges = 1; // 0 for Part 1, 1 for Part 2
+    for p in &patterns {
+        //		p.dump();
+        let vreflect = find_reflection(&p.columns(), smudges);
+        let hreflect = find_reflection(&p.rows(), smudges);
+        //        println!("h: {hreflect:?} v:{vreflect:?}");
+        if let Some(r) = vreflect {
+            sum += r;
+        }
+        if let Some(r) = hreflect {
+            sum += r * 100;
+        }
+    }
+
+    Ok(sum.into())
+    // 41566
+}
+
+#[test]
+fn test1() {
+    assert_eq!(
+        bit_errors(
+            &vec![true, false, false, true],
+            &vec![true, false, false, true]
+        ),
+        0
+    );
+    assert_eq!(
+        bit_errors(
+            &vec![true, false, false, true],
+            &vec![true, false, false, false]
+        ),
+        1
+    );
+    assert_eq!(
+        bit_errors(
+            &vec![true, false, false, true],
+            &vec![false, true, true, false]
+        ),
+        4
+    );
+}
@@ -0,0 +1,277 @@
+// Tilting platform shared by both parts of day 14
+use crate::{cycle, Answer, ParseError};
+use crate::error::parse_value;
+use crate::grid::{Cell, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Element {
+	Empty,
+	Rock,
+	Block
+}
+
+impl Cell for Element {
+	fn from_char(c: char) -> Option<Self> {
+		Some(match c {
+			'O' => Element::Rock,
+			'#' => Element::Block,
+			_ => Element::Empty
+		})
+	}
+
+	fn to_char(&self) -> char {
+		match self {
+			Element::Empty => '.',
+			Element::Rock => 'O',
+			Element::Block => '#',
+		}
+	}
+}
+
+// Direction to tilt platform in - rocks roll towards that edge
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+	North,
+	West,
+	South,
+	East,
+}
+
+impl Direction {
+	pub fn from_char(c: char) -> Option<Self> {
+		match c.to_ascii_uppercase() {
+			'N' => Some(Direction::North),
+			'W' => Some(Direction::West),
+			'S' => Some(Direction::South),
+			'E' => Some(Direction::East),
+			_ => None,
+		}
+	}
+}
+
+// North, west, south then east
+pub const SPIN: [Direction; 4] = [Direction::North, Direction::West, Direction::South, Direction::East];
+
+// Run of tilts, repeated some number of times
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tilts {
+	pub directions: Vec<Direction>,
+	pub repeat: usize,
+}
+
+// Commands separated by spaces or commas, each some directions and an optional repeat count
+// eg. "N", "EE", "NWSE*1000000000", "N, WS*3"
+pub fn parse_commands(text: &str) -> Result<Vec<Tilts>, ParseError> {
+	let mut commands = Vec::new();
+	for command in text.split(|c: char| c == ',' || c.is_whitespace()).filter(|c| !c.is_empty()) {
+		let (dirs, repeat) = match command.split_once('*') {
+			Some((dirs, count)) => (dirs, parse_value(count).map_err(|e| e.within(text, count))?),
+			None => (command, 1),
+		};
+
+		let mut directions = Vec::new();
+		for (i, c) in dirs.char_indices() {
+			let d = Direction::from_char(c)
+				.ok_or_else(|| ParseError::new(format!("Bad direction {c:?}")).within(text, &dirs[i..]))?;
+			directions.push(d);
+		}
+		if directions.is_empty() {
+			return Err(ParseError::new("Expected: <directions>[*<count>]").within(text, command));
+		}
+		commands.push(Tilts { directions, repeat });
+	}
+	Ok(commands)
+}
+
+// Run of cells between blocks (or edges) that rocks can roll along - starting at the cell
+// rocks roll towards, and stepping away from it
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+	start: usize,
+	step: isize,
+	len: usize,
+}
+
+#[derive(Debug)]
+pub struct Platform {
+	pub elements: Grid<Element>,
+	// Segments for each direction
+	segments: [Vec<Segment>; 4],
+}
+
+impl Platform {
+	pub fn create(input: &str) -> Result<Self, ParseError> {
+		let elements = Grid::from_text(input)?;
+		let segments = [Direction::North, Direction::West, Direction::South, Direction::East]
+			.map(|d| Self::find_segments(&elements, d));
+		Ok(Platform { elements, segments })
+	}
+
+	pub fn dump(&self) {
+		println!("-- {}x{}", self.elements.width, self.elements.height);
+		print!("{}", self.elements);
+	}
+
+	// Split each line of cells in direction into segments
+	fn find_segments(elements: &Grid<Element>, direction: Direction) -> Vec<Segment> {
+		let (w, h) = (elements.width as isize, elements.height as isize);
+
+		// First cell of each line, step along line, and line length
+		let (firsts, step, len) = match direction {
+			Direction::North => ((0..w).collect::<Vec<_>>(), w, h),
+			Direction::South => ((0..w).map(|x| (h - 1) * w + x).collect(), -w, h),
+			Direction::West => ((0..h).map(|y| y * w).collect(), 1, w),
+			Direction::East => ((0..h).map(|y| y * w + w - 1).collect(), -1, w),
+		};
+
+		let mut segments = Vec::new();
+		for first in firsts {
+			let mut start = None;
+			for i in 0..=len {
+				let offset = first + i * step;
+				if i < len && elements.cells[offset as usize] != Element::Block {
+					start = start.or(Some(offset));
+				} else if let Some(s) = start.take() {
+					segments.push(Segment { start: s as usize, step, len: ((offset - s) / step) as usize });
+				}
+			}
+		}
+		segments
+	}
+
+	// Count the rocks in each segment, then refill it with them all at the start
+	fn roll(segments: &[Segment], cells: &mut [Element]) {
+		for s in segments {
+			let offsets = (0..s.len as isize).map(|i| (s.start as isize + i * s.step) as usize);
+			let rocks = offsets.clone().map(|o| (cells[o] == Element::Rock) as usize).sum::<usize>();
+			for (i, o) in offsets.enumerate() {
+				cells[o] = if i < rocks { Element::Rock } else { Element::Empty };
+			}
+		}
+	}
+
+	pub fn tilt(&mut self, direction: Direction) {
+		Self::roll(&self.segments[direction as usize], &mut self.elements.cells);
+	}
+
+	// Tilt some other arrangement of rocks on this platform
+	pub fn tilt_elements(&self, elements: &mut Grid<Element>, direction: Direction) {
+		Self::roll(&self.segments[direction as usize], &mut elements.cells);
+	}
+
+	// Tilt in each direction of a spin cycle
+	pub fn spin_elements(&self, elements: &Grid<Element>) -> Grid<Element> {
+		let mut elements = elements.clone();
+		for d in SPIN {
+			self.tilt_elements(&mut elements, d);
+		}
+		elements
+	}
+
+	// Run tilt commands - long runs skip round any cycle in the arrangement of rocks
+	pub fn run_commands(&self, elements: &Grid<Element>, commands: &[Tilts]) -> Grid<Element> {
+		let mut elements = elements.clone();
+		for c in commands {
+			let step = |e: &Grid<Element>| {
+				let mut e = e.clone();
+				for &d in &c.directions {
+					self.tilt_elements(&mut e, d);
+				}
+				e
+			};
+			elements = cycle::nth_state(elements, step, c.repeat);
+		}
+		elements
+	}
+
+	// Load on an edge - each rock counts its distance from the opposite edge
+	pub fn load(elements: &Grid<Element>, edge: Direction) -> usize {
+		let (w, h) = (elements.width, elements.height);
+		let mut load = 0;
+		for (o, &e) in elements.cells.iter().enumerate() {
+			if e == Element::Rock {
+				let (x, y) = (o % w, o / w);
+				load += match edge {
+					Direction::North => h - y,
+					Direction::South => y + 1,
+					Direction::West => w - x,
+					Direction::East => x + 1,
+				};
+			}
+		}
+		load
+	}
+}
+
+// Load on edge after running commands on input
+pub fn solve_commands(input: &str, commands: &str, edge: Direction) -> Result<Answer, ParseError> {
+	let platform = Platform::create(input)?;
+	let commands = parse_commands(commands)?;
+	let elements = platform.run_commands(&platform.elements, &commands);
+	Ok(Platform::load(&elements, edge).into())
+}
+
+#[test]
+fn test_tilt() {
+	let mut platform = Platform::create(&std::fs::read_to_string("inputs/day14_test").unwrap()).unwrap();
+	let initial = platform.elements.clone();
+
+	// Spin results from the puzzle
+	let after_one = [
+		".....#....",
+		"....#...O#",
+		"...OO##...",
+		".OO#......",
+		".....OOO#.",
+		".O#...O#.#",
+		"....O#....",
+		"......OOOO",
+		"#...O###..",
+		"#..OO#....",
+	];
+	let after_three = [
+		".....#....",
+		"....#...O#",
+		".....##...",
+		"..O#......",
+		".....OOO#.",
+		".O#...O#.#",
+		"....O#...O",
+		".......OOO",
+		"#...O###.O",
+		"#.OOO#...O",
+	];
+	let one = platform.spin_elements(&initial);
+	assert_eq!(one.to_string(), after_one.join("\n") + "\n");
+	let three = platform.spin_elements(&platform.spin_elements(&one));
+	assert_eq!(three.to_string(), after_three.join("\n") + "\n");
+
+	platform.tilt(Direction::North);
+	assert_eq!(Platform::load(&platform.elements, Direction::North), 136);
+}
+
+#[test]
+fn test_commands() {
+	let input = std::fs::read_to_string("inputs/day14_test").unwrap();
+	let load = |commands, edge| solve_commands(&input, commands, edge).unwrap();
+
+	// Both parts
+	assert_eq!(load("N", Direction::North), Answer::Number(136));
+	assert_eq!(load("NWSE*1000000000", Direction::North), Answer::Number(64));
+
+	// Tilting away from an edge and back again is the same as tilting straight to it
+	assert_eq!(load("EE", Direction::East), load("E", Direction::East));
+	assert_eq!(load("W, E", Direction::East), load("E", Direction::East));
+	assert_eq!(load("n", Direction::South), load("S*0 N", Direction::South));
+
+	assert_eq!(
+		parse_commands("NWSE*3 ee").unwrap(),
+		vec![
+			Tilts { directions: SPIN.to_vec(), repeat: 3 },
+			Tilts { directions: vec![Direction::East; 2], repeat: 1 },
+		]
+	);
+	assert_eq!(parse_commands("NWSE*x").unwrap_err().column, 6);
+	assert_eq!(parse_commands("N NQ").unwrap_err().column, 4);
+	assert_eq!(parse_commands("N *3").unwrap_err().column, 3);
+}
@@ -0,0 +1,14 @@
+use crate::{bench, Answer, ParseError};
+use super::day14::{Direction, Platform};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let mut platform = Platform::create(input)?;
+	bench::parsed();
+//	platform.dump();
+
+	platform.tilt(Direction::North);
+
+//	platform.dump();
+	Ok(Platform::load(&platform.elements, Direction::North).into())
+	// 106186
+}
@@ -0,0 +1,19 @@
+use crate::{bench, cycle, Answer, ParseError};
+use super::day14::{Direction, Platform};
+
+const TOTAL_CYCLES:usize = 1000000000;
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let platform = Platform::create(input)?;
+	bench::parsed();
+
+	// One spin cycle
+	let spin = |elements: &_| platform.spin_elements(elements);
+
+	// Find where platform state starts repeating, and use that to skip to the spin of interest
+	let cycle = cycle::detect_cycle_hashed(platform.elements.clone(), spin);
+//	println!("Cycle: {cycle:?}");
+	let final_elements = cycle::state_at(platform.elements.clone(), spin, TOTAL_CYCLES, cycle);
+	Ok(Platform::load(&final_elements, Direction::North).into())
+	// 106390
+}
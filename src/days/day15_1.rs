@@ -1,4 +1,4 @@
-use std::fs::{self, read_to_string};
+use crate::{bench, Answer, ParseError};
 
 const HASH_LIMIT:u32 = 256;
 
@@ -13,25 +13,23 @@ fn hash(s: &str) -> u32 {
 	h
 }
 
-fn main() {
-    println!("Hello Day 15 1!");
-
-    let input = fs::read_to_string("inputs/day15")
-        .unwrap()
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let input = input
         .chars()
         .filter(|c| !c.is_whitespace())
         .collect::<String>();
 
     let steps = input.split(',').collect::<Vec<_>>();
+    bench::parsed();
 
     let mut sum = 0;
     for step in steps {
     	let h = hash(step);
-    	println!("Step: {step:?} {h}");
+//    	println!("Step: {step:?} {h}");
     	sum += h;
     }
 
-    println!("Sum: {sum}");
+    Ok(sum.into())
     // 512797
 }
 
@@ -1,4 +1,5 @@
-use std::fs::{self, read_to_string};
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
 
 const HASH_LIMIT: u32 = 256;
 
@@ -28,48 +29,54 @@ fn find_slot(slots:&[HashEntry], label:&str) -> Option<usize> {
 	None
 }
 
-fn main() {
-    println!("Hello Day 15 2!");
+#[derive(Debug)]
+enum Op {
+    Set(u32),
+    Remove,
+}
+
+// eg. "rn=1" or "cm-"
+fn parse_step(step: &str) -> Result<(&str, Op), ParseError> {
+    if let Some(label) = step.strip_suffix('-') {
+        Ok((label, Op::Remove))
+    } else if let Some((label, value)) = step.split_once('=') {
+        Ok((label, Op::Set(parse_value(value)?)))
+    } else {
+        Err(ParseError::new(format!("Bad step: {step:?}")))
+    }
+}
 
-    let input = fs::read_to_string("inputs/day15")
-        .unwrap()
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let input = input
         .chars()
         .filter(|c| !c.is_whitespace())
         .collect::<String>();
 
-    let steps = input.split(',').collect::<Vec<_>>();
+    let steps = input.split(',').map(parse_step).collect::<Result<Vec<_>, _>>()?;
+    bench::parsed();
 
     let mut hashmap = Vec::new();
     hashmap.resize(HASH_LIMIT as usize, Vec::<HashEntry>::new());
 
-    for step in steps {
-        let label_op = step
-            .split_inclusive(|c| c == '=' || c == '-')
-            .collect::<Vec<_>>();
-        let (label, op, value) = (
-            &label_op[0][0..label_op[0].len() - 1],
-            label_op[0].chars().last().unwrap(),
-            &label_op.get(1).unwrap_or(&"0").parse::<u32>().unwrap(),
-        );
+    for (label, op) in steps {
         let h = hash(label);
-        let mut slots = hashmap.get_mut(h as usize).unwrap();
+        let slots = &mut hashmap[h as usize];
         let maybe_slot = find_slot(slots, label);
         match op {
-        	'=' => {
+        	Op::Set(value) => {
         		if let Some(i) = maybe_slot {
-        			slots[i].value = *value;
+        			slots[i].value = value;
         		} else {
-        			slots.push(HashEntry { label:label.to_string(), value:*value });
+        			slots.push(HashEntry { label:label.to_string(), value });
         		}
         	}
-        	'-' => {
+        	Op::Remove => {
         		if let Some(i) = maybe_slot {
         			slots.remove(i);
         		}
         	}
-			_ => { panic!("Bad op"); }
         }
-        println!("Step: {step:?} {label} {op:?} {value}");
+//        println!("Step: {label} {op:?}");
     }
 
     let mut sum = 0;
@@ -80,6 +87,6 @@ fn main() {
 	    }
     }
 
-    println!("Sum: {sum}");
-    // 512797
+    Ok(sum.into())
+    // 262454
 }
@@ -0,0 +1,715 @@
+// Beam tracing shared by both parts of day 16
+use crate::ParseError;
+use crate::grid::{self, Cell};
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Element {
+    Empty,
+    Mirror1,
+    Mirror2,
+    SplitV,
+    SplitH,
+    Edge,
+}
+
+impl Cell for Element {
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '/' => Element::Mirror1,
+            '\\' => Element::Mirror2,
+            '|' => Element::SplitV,
+            '-' => Element::SplitH,
+            _ => Element::Empty,
+        })
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Element::Empty => '.',
+            Element::Mirror1 => '/',
+            Element::Mirror2 => '\\',
+            Element::SplitV => '|',
+            Element::SplitH => '-',
+            Element::Edge => '#',
+        }
+    }
+}
+
+// Beam about to step from x,y in direction dx,dy - entry points start just outside the grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Beam {
+    pub x: i32,
+    pub y: i32,
+    pub dx: i32,
+    pub dy: i32,
+}
+
+// Tile that a beam reached, and the direction it was going in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lit {
+    pub x: i32,
+    pub y: i32,
+    pub mask: u8,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+    elements: grid::Grid<Element>,
+    directions: grid::Grid<u8>,
+}
+
+impl Grid {
+    pub fn create(input: &str) -> Result<Self, ParseError> {
+        let elements = grid::Grid::from_text(input)?;
+        let directions = grid::Grid::new(elements.width, elements.height, 0);
+        Ok(Grid {
+            elements,
+            directions,
+        })
+    }
+
+    pub fn dump(&self) {
+        println!("-- {}x{}", self.elements.width, self.elements.height);
+        for y in 0..self.elements.height {
+            for x in 0..self.elements.width {
+                print!("{}", self.tile_char(x as i32, y as i32));
+            }
+            println!();
+        }
+    }
+
+    // Element, or the directions of beams through it if it is empty
+    fn tile_char(&self, x: i32, y: i32) -> char {
+        let e = self.at(x, y);
+        let c = self.directions(x, y);
+        if e == Element::Empty && c > 0 {
+            Self::direction_char(c)
+        } else {
+            e.to_char()
+        }
+    }
+
+    fn at(&self, x: i32, y: i32) -> Element {
+        self.elements.get_or(x, y, Element::Edge)
+    }
+
+    fn directions_mut(&mut self, x: i32, y: i32) -> &mut u8 {
+        self.directions.get_mut(x, y).expect("Out of range")
+    }
+
+    fn directions(&self, x: i32, y: i32) -> u8 {
+        self.directions.get_or(x, y, 0xff)
+    }
+
+    pub fn direction_mask(dx: i32, dy: i32) -> u8 {
+        match (dx, dy) {
+            (1, 0) => 0x1,
+            (-1, 0) => 0x2,
+            (0, 1) => 0x4,
+            (0, -1) => 0x8,
+            _ => 0,
+        }
+    }
+
+    fn direction_char(mask: u8) -> char {
+        match mask {
+            0x0 => '.',
+            0x1 => '>',
+            0x2 => '<',
+            0x4 => 'v',
+            0x8 => '^',
+            _ => char::from_digit(mask.count_ones() % 16, 16).unwrap(),
+        }
+    }
+
+    pub fn energised(&self) -> usize {
+        self.directions.cells.iter().filter(|c| **c > 0).count()
+    }
+
+    // Beams that split off are queued up to be followed later, rather than recursed into, so
+    // there is no limit on how many splits deep a beam can go
+    pub fn trace(&mut self, x: i32, y: i32, dx: i32, dy: i32, mask: u8) {
+        let mut queue = vec![(x, y, dx, dy, mask)];
+        while let Some((mut x, mut y, mut dx, mut dy, mut mask)) = queue.pop() {
+            // Follow path until not empty
+            loop {
+                x += dx;
+                y += dy;
+                if self.directions(x, y) & mask != 0 {
+                    break;
+                }
+                *self.directions_mut(x, y) |= mask;
+                match self.at(x, y) {
+                    Element::Edge => break,
+                    Element::Empty => (),
+                    Element::Mirror1 => { (dx, dy) = (-dy, -dx); mask = Self::direction_mask(dx, dy); },
+                    Element::Mirror2 => { (dx, dy) = (dy, dx); mask = Self::direction_mask(dx, dy); },
+                    Element::SplitH => {
+                        if dx == 0 {
+                            queue.push((x, y, -1, 0, Self::direction_mask(-1, 0)));
+                            queue.push((x, y, 1, 0, Self::direction_mask(1, 0)));
+                            break;
+                        }
+                    }
+                    Element::SplitV => {
+                        if dy == 0 {
+                            queue.push((x, y, 0, -1, Self::direction_mask(0, -1)));
+                            queue.push((x, y, 0, 1, Self::direction_mask(0, 1)));
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn trace_and_count(&mut self, x:i32, y:i32, dx:i32, dy:i32) -> usize {
+        self.directions.cells.fill(0);
+        self.trace(x, y, dx, dy, Grid::direction_mask(dx, dy));
+        self.energised()
+    }
+
+    // Move every beam on by one tile at a time, returning the tiles newly reached at each step -
+    // ends up with the same directions as trace()
+    pub fn trace_steps(&mut self, entry: Beam) -> Vec<Vec<Lit>> {
+        self.directions.cells.fill(0);
+        let mut steps = Vec::new();
+        let mut beams = vec![entry];
+        while !beams.is_empty() {
+            let mut lit = Vec::new();
+            let mut next = Vec::new();
+            for Beam { mut x, mut y, mut dx, mut dy } in beams {
+                x += dx;
+                y += dy;
+                // Outside the grid counts as every direction
+                let mask = Self::direction_mask(dx, dy);
+                if self.directions(x, y) & mask != 0 {
+                    continue;
+                }
+                *self.directions_mut(x, y) |= mask;
+                lit.push(Lit { x, y, mask });
+                match self.at(x, y) {
+                    Element::Mirror1 => (dx, dy) = (-dy, -dx),
+                    Element::Mirror2 => (dx, dy) = (dy, dx),
+                    Element::SplitH if dx == 0 => {
+                        next.push(Beam { x, y, dx: 1, dy: 0 });
+                        (dx, dy) = (-1, 0);
+                    }
+                    Element::SplitV if dy == 0 => {
+                        next.push(Beam { x, y, dx: 0, dy: 1 });
+                        (dx, dy) = (0, -1);
+                    }
+                    _ => (),
+                }
+                next.push(Beam { x, y, dx, dy });
+            }
+            if !lit.is_empty() {
+                steps.push(lit);
+            }
+            beams = next;
+        }
+        steps
+    }
+
+    // Play back steps from an unlit grid, calling 'frame' with the grid after each one, and the
+    // tiles that step reached
+    pub fn replay(&mut self, steps: &[Vec<Lit>], mut frame: impl FnMut(&Grid, &[Lit])) {
+        self.directions.cells.fill(0);
+        for lit in steps {
+            for l in lit {
+                *self.directions_mut(l.x, l.y) |= l.mask;
+            }
+            frame(self, lit);
+        }
+    }
+
+    // Grid with energised tiles in yellow, and the front of the beams in red - starts by moving
+    // the cursor to the top left, so that printing each frame in turn animates it
+    pub fn render_ansi(&self, front: &[Lit]) -> String {
+        let mut text = String::from("\x1b[H");
+        for y in 0..self.elements.height as i32 {
+            for x in 0..self.elements.width as i32 {
+                let colour = if front.iter().any(|l| (l.x, l.y) == (x, y)) {
+                    "\x1b[0;1;31m"
+                } else if self.directions(x, y) != 0 {
+                    "\x1b[0;33m"
+                } else {
+                    "\x1b[0;2m"
+                };
+                text.push_str(colour);
+                text.push(self.tile_char(x, y));
+            }
+            text.push_str("\x1b[0m\n");
+        }
+        text
+    }
+
+    // Binary PPM image, with 'scale' pixels across each tile - colours as render_ansi()
+    pub fn render_ppm(&self, front: &[Lit], scale: usize) -> Vec<u8> {
+        let (w, h) = (self.elements.width, self.elements.height);
+        let mut image = format!("P6\n{} {}\n255\n", w * scale, h * scale).into_bytes();
+        for y in 0..h as i32 {
+            let row = (0..w as i32)
+                .flat_map(|x| {
+                    let energised = self.directions(x, y) != 0;
+                    let rgb: [u8; 3] = match (front.iter().any(|l| (l.x, l.y) == (x, y)), energised, self.at(x, y)) {
+                        (true, _, _) => [255, 48, 16],
+                        (_, true, Element::Empty) => [224, 176, 0],
+                        (_, true, _) => [255, 240, 128],
+                        (_, false, Element::Empty) => [24, 24, 32],
+                        (_, false, _) => [112, 112, 144],
+                    };
+                    rgb.repeat(scale)
+                })
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                image.extend_from_slice(&row);
+            }
+        }
+        image
+    }
+
+    // Every way into the grid from outside - along the left and right edges, then the top and bottom
+    pub fn entries(&self) -> Vec<Beam> {
+        let (w, h) = (self.elements.width as i32, self.elements.height as i32);
+        let mut entries = Vec::new();
+        for y in 0..h {
+            entries.push(Beam { x: -1, y, dx: 1, dy: 0 });
+            entries.push(Beam { x: w, y, dx: -1, dy: 0 });
+        }
+        for x in 0..w {
+            entries.push(Beam { x, y: -1, dx: 0, dy: 1 });
+            entries.push(Beam { x, y: h, dx: 0, dy: -1 });
+        }
+        entries
+    }
+}
+
+// Random layout for stress testing - about one tile in 'spacing' has a mirror or splitter
+pub fn generate(width: usize, height: usize, spacing: u32, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut text = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        for _ in 0..width {
+            text.push(match rng.gen_range(0..spacing * 4) {
+                0 => '/',
+                1 => '\\',
+                2 => '|',
+                3 => '-',
+                _ => '.',
+            });
+        }
+        text.push('\n');
+    }
+    text
+}
+
+// Set of tiles, by offset into grid
+#[derive(Debug, Clone, PartialEq)]
+struct TileSet {
+    bits: Vec<u64>,
+}
+
+impl TileSet {
+    fn new(size: usize) -> Self {
+        TileSet { bits: vec![0; size.div_ceil(64)] }
+    }
+
+    fn insert(&mut self, offset: usize) {
+        self.bits[offset / 64] |= 1 << (offset % 64);
+    }
+
+    fn contains(&self, offset: usize) -> bool {
+        self.bits[offset / 64] & (1 << (offset % 64)) != 0
+    }
+
+    fn union_with(&mut self, other: &TileSet) {
+        for (b, o) in self.bits.iter_mut().zip(&other.bits) {
+            *b |= o;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+}
+
+// Straight run of beam, through mirrors and along splitters, until it splits or leaves the grid
+#[derive(Debug)]
+struct Segment {
+    tiles: Vec<usize>,
+    // Splitter that the beam splits at
+    split: Option<usize>,
+}
+
+// Tiles lit by beams out of each component
+#[derive(Debug)]
+enum Reach {
+    // All of them, found up front
+    Sets(Vec<TileSet>),
+    // Just the component's own, and the components its beams go on to - the rest are gathered
+    // when asked for
+    Walk { tiles: Vec<Vec<usize>>, next: Vec<Vec<usize>> },
+}
+
+// Beams through the grid, worked out once for every entry point
+//
+// Each splitter that a beam can split at is a node - the tiles lit by the two beams out of it,
+// and the splitters those beams split at in turn. Loops of splitters are collapsed into one
+// component, then the tiles lit from each component are found, leaves first. A set for every
+// component would take too much memory on large grids, so past a limit they are not kept.
+#[derive(Debug)]
+pub struct BeamGraph {
+    width: usize,
+    height: usize,
+    // Splitter node at each tile
+    splitters: grid::Grid<u32>,
+    // Component that each splitter is in
+    component: Vec<usize>,
+    reach: Reach,
+    // Path from each entry point to the first splitter
+    entries: Vec<(Beam, Segment)>,
+}
+
+const NO_SPLITTER: u32 = u32::MAX;
+
+// Most 64 tile words that the sets for all components can take - 32MB
+const SET_WORDS_LIMIT: usize = 1 << 22;
+
+impl BeamGraph {
+    pub fn create(grid: &Grid) -> Self {
+        Self::create_with_limit(grid, SET_WORDS_LIMIT)
+    }
+
+    fn create_with_limit(grid: &Grid, limit: usize) -> Self {
+        let (width, height) = (grid.elements.width, grid.elements.height);
+
+        let mut positions = Vec::new();
+        let mut splitters = grid::Grid::new(width, height, NO_SPLITTER);
+        for y in 0..height as i32 {
+            for x in 0..width as i32 {
+                if matches!(grid.at(x, y), Element::SplitH | Element::SplitV) {
+                    splitters.set(x, y, positions.len() as u32);
+                    positions.push((x, y));
+                }
+            }
+        }
+
+        // Tiles lit by each splitter's own beams, and the splitters they lead on to
+        let mut tiles = Vec::new();
+        let mut next = Vec::new();
+        for &(x, y) in &positions {
+            let beams = match grid.at(x, y) {
+                Element::SplitH => [(1, 0), (-1, 0)],
+                _ => [(0, 1), (0, -1)],
+            };
+            let mut t = vec![grid.elements.offset(x, y).unwrap()];
+            let mut n = Vec::new();
+            for (dx, dy) in beams {
+                let segment = Self::trace_segment(grid, &splitters, Beam { x, y, dx, dy });
+                t.extend(segment.tiles);
+                n.extend(segment.split);
+            }
+            tiles.push(t);
+            next.push(n);
+        }
+
+        // Components come out leaves first, so everything a component leads to is done before it
+        let components = strongly_connected(&next);
+        let mut component = vec![0; positions.len()];
+        for (c, nodes) in components.iter().enumerate() {
+            for &n in nodes {
+                component[n] = c;
+            }
+        }
+        let reach = if components.len().saturating_mul((width * height).div_ceil(64)) <= limit {
+            let mut lit: Vec<TileSet> = Vec::with_capacity(components.len());
+            for nodes in &components {
+                let mut set = TileSet::new(width * height);
+                for &n in nodes {
+                    for &t in &tiles[n] {
+                        set.insert(t);
+                    }
+                    for &m in &next[n] {
+                        if component[m] != lit.len() {
+                            set.union_with(&lit[component[m]]);
+                        }
+                    }
+                }
+                lit.push(set);
+            }
+            Reach::Sets(lit)
+        } else {
+            let mut component_tiles = Vec::with_capacity(components.len());
+            let mut component_next = Vec::with_capacity(components.len());
+            for (c, nodes) in components.iter().enumerate() {
+                let mut n = nodes.iter().flat_map(|&n| &next[n]).map(|&m| component[m]).filter(|&m| m != c).collect::<Vec<_>>();
+                n.sort();
+                n.dedup();
+                component_tiles.push(nodes.iter().flat_map(|&n| std::mem::take(&mut tiles[n])).collect());
+                component_next.push(n);
+            }
+            Reach::Walk { tiles: component_tiles, next: component_next }
+        };
+
+        let entries = grid.entries().into_iter().map(|e| (e, Self::trace_segment(grid, &splitters, e))).collect();
+
+        BeamGraph { width, height, splitters, component, reach, entries }
+    }
+
+    // Follow beam until it splits, leaves the grid, or comes back round to where it started
+    fn trace_segment(grid: &Grid, splitters: &grid::Grid<u32>, start: Beam) -> Segment {
+        let Beam { mut x, mut y, mut dx, mut dy } = start;
+        let mut tiles = Vec::new();
+        loop {
+            x += dx;
+            y += dy;
+            if (Beam { x, y, dx, dy }) == start {
+                return Segment { tiles, split: None };
+            }
+            match grid.at(x, y) {
+                Element::Edge => return Segment { tiles, split: None },
+                Element::Empty => (),
+                Element::Mirror1 => (dx, dy) = (-dy, -dx),
+                Element::Mirror2 => (dx, dy) = (dy, dx),
+                Element::SplitH => {
+                    if dx == 0 {
+                        return Segment { tiles, split: Some(splitters.get(x, y).unwrap() as usize) };
+                    }
+                }
+                Element::SplitV => {
+                    if dy == 0 {
+                        return Segment { tiles, split: Some(splitters.get(x, y).unwrap() as usize) };
+                    }
+                }
+            }
+            tiles.push(grid.elements.offset(x, y).unwrap());
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = Beam> + '_ {
+        self.entries.iter().map(|(e, _)| *e)
+    }
+
+    fn entry_segment(&self, entry: Beam) -> &Segment {
+        let (_, segment) = self.entries.iter().find(|(e, _)| *e == entry).expect("Not an entry point");
+        segment
+    }
+
+    // All tiles lit by a beam along segment, and the beams it splits into
+    fn lit_by(&self, segment: &Segment) -> TileSet {
+        let mut set = match (&self.reach, segment.split) {
+            (Reach::Sets(lit), Some(s)) => lit[self.component[s]].clone(),
+            (Reach::Walk { tiles, next }, Some(s)) => {
+                let mut set = TileSet::new(self.width * self.height);
+                let mut seen = vec![false; tiles.len()];
+                let mut pending = vec![self.component[s]];
+                while let Some(c) = pending.pop() {
+                    if !std::mem::replace(&mut seen[c], true) {
+                        tiles[c].iter().for_each(|&t| set.insert(t));
+                        pending.extend(&next[c]);
+                    }
+                }
+                set
+            }
+            (_, None) => TileSet::new(self.width * self.height),
+        };
+        for &t in &segment.tiles {
+            set.insert(t);
+        }
+        set
+    }
+
+    // Number of tiles energised by beam from entry point
+    pub fn energised(&self, entry: Beam) -> usize {
+        self.lit_by(self.entry_segment(entry)).len()
+    }
+
+    // Most tiles energised from any entry point
+    pub fn most_energised(&self) -> usize {
+        self.entries().map(|e| self.energised(e)).max().unwrap_or(0)
+    }
+
+    // Entry points whose beams energise tile x,y
+    pub fn entries_energising(&self, x: i32, y: i32) -> Vec<Beam> {
+        let Some(offset) = self.splitters.offset(x, y) else {
+            return Vec::new();
+        };
+        self.entries
+            .iter()
+            .filter(|(_, s)| self.lit_by(s).contains(offset))
+            .map(|(e, _)| *e)
+            .collect()
+    }
+}
+
+// Tarjan's algorithm, without recursion - strongly connected components of the graph given by
+// each node's successors, with components coming before any that lead to them
+fn strongly_connected(next: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    const UNVISITED: usize = usize::MAX;
+    let mut index = vec![UNVISITED; next.len()];
+    let mut low = vec![0; next.len()];
+    let mut on_stack = vec![false; next.len()];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut count = 0;
+
+    for root in 0..next.len() {
+        if index[root] != UNVISITED {
+            continue;
+        }
+        // Node, and how many of its successors have been looked at
+        let mut calls = vec![(root, 0)];
+        while let Some(&mut (n, ref mut i)) = calls.last_mut() {
+            if *i == 0 {
+                index[n] = count;
+                low[n] = count;
+                count += 1;
+                stack.push(n);
+                on_stack[n] = true;
+            }
+            if let Some(&m) = next[n].get(*i) {
+                *i += 1;
+                if index[m] == UNVISITED {
+                    calls.push((m, 0));
+                } else if on_stack[m] {
+                    low[n] = low[n].min(index[m]);
+                }
+                continue;
+            }
+
+            // All successors done - n is the root of a component if nothing below reaches higher
+            calls.pop();
+            if low[n] == index[n] {
+                let mut component = Vec::new();
+                loop {
+                    let m = stack.pop().unwrap();
+                    on_stack[m] = false;
+                    component.push(m);
+                    if m == n {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some(&(parent, _)) = calls.last() {
+                low[parent] = low[parent].min(low[n]);
+            }
+        }
+    }
+    components
+}
+
+#[test]
+fn test_graph() {
+    // Splitter whose beams mirror round back into it
+    let looped = ["..|..", "./-\\.", ".\\./."].join("\n");
+
+    for input in [std::fs::read_to_string("inputs/day16_test").unwrap(), looped] {
+        let mut grid = Grid::create(&input).unwrap();
+        let graph = BeamGraph::create(&grid);
+        // No room for sets, so tiles are gathered for each entry
+        let walked = BeamGraph::create_with_limit(&grid, 0);
+        assert!(matches!(walked.reach, Reach::Walk { .. }));
+        for e in graph.entries() {
+            let energised = grid.trace_and_count(e.x, e.y, e.dx, e.dy);
+            assert_eq!(graph.energised(e), energised, "{e:?}");
+            assert_eq!(walked.energised(e), energised, "{e:?}");
+        }
+
+        for y in 0..grid.elements.height as i32 {
+            for x in 0..grid.elements.width as i32 {
+                let traced = graph
+                    .entries()
+                    .filter(|e| {
+                        grid.trace_and_count(e.x, e.y, e.dx, e.dy);
+                        grid.directions(x, y) != 0
+                    })
+                    .collect::<Vec<_>>();
+                assert_eq!(graph.entries_energising(x, y), traced, "{x},{y}");
+                assert_eq!(walked.entries_energising(x, y), traced, "{x},{y}");
+            }
+        }
+    }
+
+    let grid = Grid::create(&std::fs::read_to_string("inputs/day16_test").unwrap()).unwrap();
+    let graph = BeamGraph::create(&grid);
+    assert_eq!(graph.energised(Beam { x: -1, y: 0, dx: 1, dy: 0 }), 46);
+    assert_eq!(graph.energised(Beam { x: 3, y: -1, dx: 0, dy: 1 }), 51);
+    assert_eq!(graph.most_energised(), 51);
+}
+
+#[test]
+fn test_steps() {
+    let input = std::fs::read_to_string("inputs/day16_test").unwrap();
+    let mut traced = Grid::create(&input).unwrap();
+    traced.trace_and_count(-1, 0, 1, 0);
+
+    let mut grid = Grid::create(&input).unwrap();
+    let steps = grid.trace_steps(Beam { x: -1, y: 0, dx: 1, dy: 0 });
+    assert_eq!(grid.directions, traced.directions);
+    assert_eq!(steps[0], vec![Lit { x: 0, y: 0, mask: 0x1 }]);
+    assert_eq!(steps[1], vec![Lit { x: 1, y: 0, mask: 0x1 }]);
+    // Splits at 1,0 and goes down
+    assert_eq!(steps[2], vec![Lit { x: 1, y: 1, mask: 0x4 }]);
+
+    // Each frame has one more step lit up
+    let mut energised = Vec::new();
+    let mut frames = Vec::new();
+    grid.replay(&steps, |g, lit| {
+        energised.push(g.energised());
+        frames.push((g.render_ansi(lit), g.render_ppm(lit, 2)));
+    });
+    assert_eq!(frames.len(), steps.len());
+    assert!(energised.windows(2).all(|e| e[0] <= e[1]));
+    assert_eq!(energised.last(), Some(&46));
+    assert_eq!(grid.directions, traced.directions);
+
+    let (ansi, ppm) = frames.last().unwrap();
+    let plain = ansi.replace("\x1b[H", "").split('\x1b').map(|s| s.split_once('m').map_or(s, |(_, t)| t)).collect::<String>();
+    let mut expected = String::new();
+    for y in 0..10 {
+        expected.extend((0..10).map(|x| traced.tile_char(x, y)));
+        expected.push('\n');
+    }
+    assert_eq!(plain, expected);
+
+    let header = b"P6\n20 20\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 20 * 20 * 3);
+}
+
+#[test]
+fn test_generated() {
+    // Tracer and graph agree on other layouts
+    for seed in 0..4 {
+        let mut grid = Grid::create(&generate(60, 40, 3, seed)).unwrap();
+        let graph = BeamGraph::create(&grid);
+        for e in graph.entries() {
+            assert_eq!(graph.energised(e), grid.trace_and_count(e.x, e.y, e.dx, e.dy), "{seed} {e:?}");
+        }
+    }
+
+    // Too big to keep a set for every component
+    let mut grid = Grid::create(&generate(1000, 1000, 3, 0)).unwrap();
+    let graph = BeamGraph::create(&grid);
+    assert!(matches!(graph.reach, Reach::Walk { .. }));
+    let e = Beam { x: -1, y: 0, dx: 1, dy: 0 };
+    assert_eq!(graph.energised(e), grid.trace_and_count(e.x, e.y, e.dx, e.dy));
+
+    // Far more splits deep than the stack would allow if each one recursed
+    let mut grid = Grid::create(&generate(2000, 2000, 3, 16)).unwrap();
+    let energised = grid.trace_and_count(-1, 0, 1, 0);
+    assert!(energised > 3_000_000 && energised <= 2000 * 2000, "{energised}");
+}
+
+#[test]
+fn test_strongly_connected() {
+    // 0 -> 1 <-> 2 -> 3, 3 -> 3
+    let next = vec![vec![1], vec![2], vec![1, 3], vec![3]];
+    let mut components = strongly_connected(&next);
+    components.iter_mut().for_each(|c| c.sort());
+    assert_eq!(components, vec![vec![3], vec![1, 2], vec![0]]);
+}
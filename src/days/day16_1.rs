@@ -0,0 +1,12 @@
+use crate::{bench, Answer, ParseError};
+use super::day16::Grid;
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut grid = Grid::create(input)?;
+    bench::parsed();
+
+    grid.trace(-1, 0, 1, 0, Grid::direction_mask(1, 0));
+
+//    grid.dump();
+    Ok(grid.energised().into())
+}
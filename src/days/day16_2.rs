@@ -0,0 +1,12 @@
+use crate::{bench, Answer, ParseError};
+use super::day16::{BeamGraph, Grid};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let grid = Grid::create(input)?;
+    bench::parsed();
+//    grid.dump();
+
+    let graph = BeamGraph::create(&grid);
+
+    Ok(graph.most_energised().into())
+}
@@ -0,0 +1,228 @@
+// Crucible route finding shared by both parts of day 17
+use crate::ParseError;
+use crate::grid::{Cell, Grid};
+use crate::search::{self, SearchState};
+
+// How a crucible may move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrucibleRules {
+    // Steps in one direction before it can turn, or stop at the end
+    pub min_straight: usize,
+    // Most steps in one direction before it must turn
+    pub max_straight: usize,
+    // Can turn back the way it came, as well as left and right
+    pub allow_reverse: bool,
+    // Extra heat lost on each turn
+    pub turn_cost: usize,
+}
+
+impl CrucibleRules {
+    pub const PART1: CrucibleRules = CrucibleRules {
+        min_straight: 1,
+        max_straight: 3,
+        allow_reverse: false,
+        turn_cost: 0,
+    };
+
+    pub const PART2: CrucibleRules = CrucibleRules {
+        min_straight: 4,
+        max_straight: 10,
+        allow_reverse: false,
+        turn_cost: 0,
+    };
+}
+
+#[derive(Debug)]
+pub struct Map {
+    pub losses: Grid<u8>,
+}
+
+// Crucible position, the direction it is moving in, and how many steps it has made in that direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crucible {
+    pub x: i32,
+    pub y: i32,
+    pub direction: u8,
+    pub steps: usize,
+}
+
+// Search for a path across map to 'end'
+struct Route<'a> {
+    map: &'a Map,
+    rules: &'a CrucibleRules,
+    end: (i32, i32),
+    // Least heat lost on any block
+    min_loss: usize,
+}
+
+impl SearchState<Route<'_>> for Crucible {
+    fn successors(&self, route: &Route) -> Vec<(Self, usize)> {
+        let rules = route.rules;
+        let mut next = Vec::new();
+        let mut push_step = |direction: u8, steps: usize, extra_cost: usize| {
+            let (x, y) = Map::forward(self.x, self.y, direction);
+            if let Some(c) = route.map.at(x, y) {
+                next.push((Crucible { x, y, direction, steps }, c as usize + extra_cost));
+            }
+        };
+
+        //   Forward
+        if self.steps < rules.max_straight {
+            push_step(self.direction, self.steps + 1, 0);
+        }
+
+        // Turns - but not straight from the start, which already goes in every direction
+        if self.steps > 0 && self.steps >= rules.min_straight {
+            push_step(Map::left(self.direction), 1, rules.turn_cost);
+            push_step(Map::right(self.direction), 1, rules.turn_cost);
+            if rules.allow_reverse {
+                push_step(Map::reverse(self.direction), 1, rules.turn_cost);
+            }
+        }
+
+        next
+    }
+
+    fn is_goal(&self, route: &Route) -> bool {
+        (self.x, self.y) == route.end && self.steps >= route.rules.min_straight
+    }
+
+    // Every step loses at least the least of any block - which may be 0
+    fn heuristic(&self, route: &Route) -> usize {
+        (self.x.abs_diff(route.end.0) + self.y.abs_diff(route.end.1)) as usize * route.min_loss
+    }
+}
+
+impl Map {
+    pub fn create(input: &str) -> Result<Self, ParseError> {
+        Ok(Map {
+            losses: Grid::from_text(input)?,
+        })
+    }
+
+    pub fn direction_to_char(direction: u8) -> char {
+        match direction {
+            0x0 => '>',
+            0x1 => 'v',
+            0x2 => '<',
+            0x3 => '^',
+            _ => '.',
+        }
+    }
+
+    // Map with each step of path shown as an arrow in the direction it moved
+    pub fn render_path(&self, path: &[Crucible]) -> String {
+        let mut chars = self
+            .losses
+            .rows()
+            .map(|r| r.iter().map(Cell::to_char).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        // First is the start, which has not moved
+        for c in path.iter().skip(1) {
+            chars[c.y as usize][c.x as usize] = Self::direction_to_char(c.direction);
+        }
+        chars.into_iter().map(|r| r.into_iter().collect::<String>() + "\n").collect()
+    }
+
+    fn at(&self, x: i32, y: i32) -> Option<u8> {
+        self.losses.get(x, y)
+    }
+
+    fn forward(x: i32, y: i32, direction: u8) -> (i32, i32) {
+        match direction {
+            0 => (x + 1, y),
+            1 => (x, y + 1),
+            2 => (x - 1, y),
+            3 => (x, y - 1),
+            _ => panic!("Bad direction"),
+        }
+    }
+
+    fn left(direction: u8) -> u8 {
+        (direction + 3) & 3
+    }
+    fn right(direction: u8) -> u8 {
+        (direction + 1) & 3
+    }
+    fn reverse(direction: u8) -> u8 {
+        (direction + 2) & 3
+    }
+
+    // Lowest heat loss from start to end, and the steps taken from the start, or None if rules
+    // don't allow a way there
+    pub fn find_path(&self, start: (i32, i32), end: (i32, i32), rules: &CrucibleRules) -> Option<(usize, Vec<Crucible>)> {
+        let min_loss = self.losses.rows().flat_map(|r| r.iter()).min().map_or(0, |&l| l as usize);
+        let route = Route { map: self, rules, end, min_loss };
+
+        // Start off in every direction
+        let starts = (0..4).map(|direction| Crucible {
+            x: start.0,
+            y: start.1,
+            direction,
+            steps: 0,
+        });
+
+        search::astar(&route, starts).map(|p| (p.cost, p.states))
+    }
+
+    // From top left to bottom right
+    pub fn find_corner_path(&self, rules: &CrucibleRules) -> Option<(usize, Vec<Crucible>)> {
+        let end = (self.losses.width as i32 - 1, self.losses.height as i32 - 1);
+        self.find_path((0, 0), end, rules)
+    }
+}
+
+#[test]
+fn test_rules() {
+    let map = Map::create(&std::fs::read_to_string("inputs/day17_test").unwrap()).unwrap();
+    let cost = |m: &Map, rules: &CrucibleRules| m.find_corner_path(rules).map(|(c, _)| c);
+    assert_eq!(cost(&map, &CrucibleRules::PART1), Some(102));
+    assert_eq!(cost(&map, &CrucibleRules::PART2), Some(94));
+
+    // Straight line longer than 16 steps
+    let line = Map::create(&"1".repeat(25)).unwrap();
+    let rules = |max_straight| CrucibleRules { max_straight, ..CrucibleRules::PART1 };
+    assert_eq!(cost(&line, &rules(24)), Some(24));
+    assert_eq!(cost(&line, &rules(20)), None);
+
+    // Reversing lets it shuffle along the line - 20 forward, 1 back, then 5 forward
+    let reverse = CrucibleRules { allow_reverse: true, ..rules(20) };
+    assert_eq!(cost(&line, &reverse), Some(26));
+
+    let square = Map::create("11\n11").unwrap();
+    let turn_cost = CrucibleRules { turn_cost: 5, ..CrucibleRules::PART1 };
+    assert_eq!(cost(&square, &CrucibleRules::PART1), Some(2));
+    assert_eq!(cost(&square, &turn_cost), Some(7));
+
+    // Blocks that lose nothing - counting 1 a step to the end would miss the free way round the 1
+    let zeros = Map::create("00\n00\n01\n00").unwrap();
+    assert_eq!(cost(&zeros, &CrucibleRules::PART1), Some(0));
+}
+
+#[test]
+fn test_path() {
+    let map = Map::create(&std::fs::read_to_string("inputs/day17_test").unwrap()).unwrap();
+    let (cost, path) = map.find_corner_path(&CrucibleRules::PART1).unwrap();
+    assert_eq!(path.first().map(|c| (c.x, c.y)), Some((0, 0)));
+    assert_eq!(path.last().map(|c| (c.x, c.y)), Some((12, 12)));
+    let loss = path.iter().skip(1).map(|c| map.at(c.x, c.y).unwrap() as usize).sum::<usize>();
+    assert_eq!(loss, cost);
+
+    // As drawn in the puzzle
+    let expected = [
+        "2>>34^>>>1323",
+        "32v>>>35v5623",
+        "32552456v>>54",
+        "3446585845v52",
+        "4546657867v>6",
+        "14385987984v4",
+        "44578769877v6",
+        "36378779796v>",
+        "465496798688v",
+        "456467998645v",
+        "12246868655<v",
+        "25465488877v5",
+        "43226746555v>",
+    ];
+    assert_eq!(map.render_path(&path), expected.join("\n") + "\n");
+}
@@ -0,0 +1,12 @@
+use crate::{bench, Answer, ParseError};
+use super::day17::{CrucibleRules, Map};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let map = Map::create(input)?;
+    bench::parsed();
+
+    let lowest_cost = map.find_corner_path(&CrucibleRules::PART2).map(|(cost, _)| cost);
+
+    Ok(lowest_cost.into())
+    // 801
+}
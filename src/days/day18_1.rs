@@ -0,0 +1,90 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
+
+#[derive(Debug, Clone, Copy)]
+struct Colour(u8, u8, u8);
+
+impl Colour {
+	fn from_string(s: &str) -> Result<Colour, ParseError> {
+	    let cs = s.chars().collect::<Vec<_>>();
+	    if cs.len() != 7 || cs[0] != '#' {
+	        return Err(ParseError::new(format!("Bad colour: {s}")));
+	    }
+	    let mut d: [_; 6] = [0; 6];
+	    for i in 0..=5 {
+	        d[i] = cs[i + 1].to_digit(16)
+	        	.ok_or_else(|| ParseError::new(format!("Bad hex digit: {}", cs[i + 1])).with_column(i + 2))?;
+	    }
+	    Ok(Colour(
+	        (d[0] * 16 + d[1]) as u8,
+	        (d[2] * 16 + d[3]) as u8,
+	        (d[4] * 16 + d[5]) as u8,
+	    ))
+	}
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cmd {
+	cmd: char,
+	distance: usize,
+	colour: Colour
+}
+
+impl Cmd {
+	// eg. "R 6 (#70c710)"
+	fn from_string(l: &str) -> Result<Cmd, ParseError> {
+	    let parens : &[_] = &['(',')'];
+	    let split_line = l.split_whitespace().collect::<Vec<_>>();
+	    if split_line.len() != 3 {
+	    	return Err(ParseError::new("Expected: <direction> <distance> (<colour>)"));
+	    }
+	    let cmd = match split_line[0] {
+	    	"U" | "D" | "L" | "R" => split_line[0].chars().next().unwrap(),
+	    	c => return Err(ParseError::new(format!("Unknown command: {c}")).within(l, split_line[0])),
+	    };
+	    let distance = parse_value(split_line[1]).map_err(|e| e.within(l, split_line[1]))?;
+	    let colour_text = split_line[2].trim_matches(parens);
+	    let colour = Colour::from_string(colour_text).map_err(|e| e.within(l, colour_text))?;
+		Ok(Cmd { cmd, distance, colour })
+	}
+}
+
+fn apply_commands<F>(commands: &[Cmd], mut func: F)
+	where F: FnMut(i32, i32, i32, Colour) {
+	let mut x = 0;
+	let mut y = 0;
+
+	for c in commands {
+		match c.cmd {
+			'U' => y -= (c.distance) as i32,
+			'D' => y += (c.distance) as i32,
+			'L' => x -= (c.distance) as i32,
+			'R' => x += (c.distance) as i32,
+			_ => panic!("Unknown command: {}", c.cmd)
+		}
+		func(x, y, c.distance as i32, c.colour);
+	}
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+
+	let commands = parse_lines(input, Cmd::from_string)?;
+bench::parsed();
+
+    let mut px = 0;
+    let mut py = 0;
+    let mut area = 0;
+    let mut distance = 0;
+    apply_commands(&commands, |x,y,d,_c| {
+    	if y > py {
+    		area += (y-py) * x;
+    	} else if y < py {
+    		area += (y-py) * x;
+	   	}
+    	distance += d;
+    	(px,py) = (x,y);
+    });
+
+   	Ok((area+(distance/2)+1).into())
+   	// 58550
+}
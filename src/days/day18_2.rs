@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_lines;
 
 #[derive(Debug, Clone, Copy)]
 struct Cmd {
@@ -6,28 +7,29 @@ struct Cmd {
 	distance: usize
 }
 
-fn cmd_from_colour(s: &str) -> Option<(char, usize)> {
+fn cmd_from_colour(s: &str) -> Result<(char, usize), ParseError> {
     let cs = s.chars().collect::<Vec<_>>();
     if cs.len() != 7 || cs[0] != '#' {
-        return None;
+        return Err(ParseError::new(format!("Bad colour: {s}")));
     }
     let mut d: [_; 6] = [0; 6];
     for i in 0..=5 {
-        d[i] = cs[i + 1].to_digit(16)?;
+        d[i] = cs[i + 1].to_digit(16)
+        	.ok_or_else(|| ParseError::new(format!("Bad hex digit: {}", cs[i + 1])).with_column(i + 2))?;
     }
     let cmd = match d[5] {
 	    0 => 'R',
 	    1 => 'D',
 	    2 => 'L',
 	    3 => 'U',
-	    _ => panic!("Bad command: {}", d[5])
+	    _ => return Err(ParseError::new(format!("Bad command: {}", d[5])).with_column(7))
     };
 
     let mut dist = 0;
     for i in 0..=4 {
     	dist = dist * 16 + d[i] as usize;
     }
-    Some((cmd,dist))
+    Ok((cmd,dist))
 }
 
 fn apply_commands<F>(commands: &[Cmd], mut func: F)
@@ -47,18 +49,17 @@ fn apply_commands<F>(commands: &[Cmd], mut func: F)
 	}
 }
 
-fn main() {
-    println!("Hello Day 18 2!");
-    let input = fs::read_to_string("inputs/day18").unwrap();
-
-	let mut commands = Vec::new();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
 
     let parens : &[_] = &['(',')'];
-    for l in input.lines() {
-        let split_line = l.split_whitespace().collect::<Vec<_>>();
-        let (cmd, dist) = cmd_from_colour(split_line[2].trim_matches(parens)).unwrap();
-		commands.push(Cmd { cmd, distance: dist });
-    }
+	let commands = parse_lines(input, |l| {
+        let colour = l.split_whitespace().nth(2)
+        	.ok_or(ParseError::new("Expected: <direction> <distance> (<colour>)"))?
+        	.trim_matches(parens);
+        let (cmd, dist) = cmd_from_colour(colour).map_err(|e| e.within(l, colour))?;
+		Ok(Cmd { cmd, distance: dist })
+    })?;
+bench::parsed();
 
     let mut px = 0;
     let mut py = 0;
@@ -72,6 +73,6 @@ fn main() {
     	(px,py) = (x,y);
     });
 
-   	println!("Area: {}", area+(distance as i64 /2)+1 );
+   	Ok((area+(distance as i64 /2)+1).into())
    	// 47452118468566
 }
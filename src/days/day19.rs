@@ -0,0 +1,1397 @@
+// Part rating workflows shared by both parts of day 19
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::ops::RangeInclusive;
+
+use crate::ParseError;
+use crate::error::parse_value;
+
+// Values each attribute can take in the puzzle
+pub const PUZZLE_DOMAIN: RangeInclusive<usize> = 1..=4000;
+
+// Attributes parts are rated on, and the values each can take
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schema {
+    pub names: Vec<String>,
+    pub domain: RangeInclusive<usize>,
+}
+
+impl Schema {
+    pub fn new(names: &[&str], domain: RangeInclusive<usize>) -> Self {
+        Schema { names: names.iter().map(|n| n.to_string()).collect(), domain }
+    }
+
+    // Every attribute named in the parts, then any others only used in rules - names are runs of
+    // letters, eg. "x" in "{x=787,...}" or "x<1416:A". With no parts, the puzzle's x, m, a and s
+    // come first, so that rules alone are counted over the same parts as the puzzle.
+    pub fn infer(rule_lines: &[&str], part_lines: &[&str], domain: RangeInclusive<usize>) -> Self {
+        let names = if part_lines.is_empty() { vec!["x", "m", "a", "s"] } else { Vec::new() };
+        let mut schema = Schema::new(&names, domain);
+        let mut add = |text: &str| {
+            for name in text.split(|c: char| !c.is_ascii_alphabetic()).filter(|n| !n.is_empty()) {
+                if schema.index(name).is_none() {
+                    schema.names.push(name.to_string());
+                }
+            }
+        };
+        for l in part_lines {
+            for attribute in l.split(',') {
+                add(attribute.split_once('=').map_or("", |(name, _)| name));
+            }
+        }
+        for l in rule_lines {
+            let rules = l.split_once('{').map_or("", |(_, rules)| rules);
+            for rule in rules.split(',') {
+                add(rule.split_once(':').map_or("", |(cond, _)| cond));
+            }
+        }
+        schema
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+
+    fn variable_to_index(&self, v: &str) -> Result<usize, ParseError> {
+        self.index(v).ok_or_else(|| ParseError::new(format!("Bad var: {v}")))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Operation {
+    Less(usize, usize),
+    Greater(usize, usize),
+    // Anything else - index of the condition
+    Test(usize),
+    Always,
+    Accept,
+    Reject
+}
+
+impl Operation {
+    // eg. "a<2006"
+    fn from_string(s:&str, schema: &Schema) -> Result<Self, ParseError> {
+        let var_len = s.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(s.len());
+        let (var, rest) = s.split_at(var_len);
+        let mut ci = rest.chars();
+        let Some(op) = ci.next() else {
+            return Err(ParseError::new(format!("Bad condition: {s}")));
+        };
+        let var = schema.variable_to_index(var).map_err(|e| e.with_column(1))?;
+        let value = parse_value(ci.as_str()).map_err(|e| e.with_column(var_len + 2))?;
+        match op {
+            '<' => Ok(Operation::Less(var, value)),
+            '>' => Ok(Operation::Greater(var, value)),
+            _ => Err(ParseError::new(format!("Bad op: {op}")).with_column(var_len + 1))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    // Value of each attribute in the schema
+    pub vars: Vec<usize>
+}
+
+impl Part {
+    // eg. "{x=787,m=2655,a=1222,s=2876}" - attributes not given are 0
+    pub fn from_string(s:&str, schema: &Schema) -> Result<Self, ParseError> {
+
+        let mut r = Part { vars: vec![0; schema.names.len()] };
+
+        let parens : &[_] = &['{','}'];
+
+        for v in s.trim_matches(parens).split(',') {
+            let (name, value) = v.split_once('=')
+                .ok_or_else(|| ParseError::new(format!("Bad attribute: {v}")).within(s, v))?;
+            let var = schema.variable_to_index(name).map_err(|e| e.within(s, name))?;
+            r.vars[var] = parse_value(value).map_err(|e| e.within(s, value))?;
+        }
+
+        Ok(r)
+    }
+
+    pub fn sum(&self) -> usize {
+        self.vars.iter().sum()
+    }
+}
+
+// Box of parts - every combination of values from min to max inclusive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartRange {
+    pub min: Vec<usize>,
+    pub max: Vec<usize>
+}
+
+impl PartRange {
+    pub fn full_range(schema: &Schema) -> PartRange {
+        PartRange { min: vec![*schema.domain.start(); schema.names.len()], max: vec![*schema.domain.end(); schema.names.len()] }
+    }
+
+    // None if there are too many to count
+    pub fn combinations(&self) -> Option<usize> {
+        let mut c: usize = 1;
+        for i in 0..self.min.len() {
+            c = c.checked_mul((self.max[i]+1) - self.min[i])?;
+        }
+        Some(c)
+    }
+
+    pub fn contains(&self, part: &Part) -> bool {
+        (0..self.min.len()).all(|i| self.min[i] <= part.vars[i] && part.vars[i] <= self.max[i])
+    }
+
+    pub fn intersects(&self, other: &PartRange) -> bool {
+        (0..self.min.len()).all(|i| self.min[i] <= other.max[i] && other.min[i] <= self.max[i])
+    }
+}
+
+// Weighted sum of attributes is at most bound - sum(coefficients[i] * vars[i]) <= bound
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Constraint {
+    pub coefficients: Vec<i64>,
+    pub bound: i64,
+}
+
+impl Constraint {
+    // Parts can be outside the domain, so may need more than i64
+    pub fn holds(&self, part: &Part) -> bool {
+        self.coefficients.iter().zip(&part.vars).map(|(&c, &v)| c as i128 * v as i128).sum::<i128>() <= self.bound as i128
+    }
+
+    // Values are whole numbers, so the opposite of <= bound is >= bound+1
+    pub fn negated(&self) -> Constraint {
+        Constraint { coefficients: self.coefficients.iter().map(|c| -c).collect(), bound: -self.bound - 1 }
+    }
+
+    // Smallest and largest the sum can be over range, leaving out attribute 'except'
+    fn limits(&self, range: &PartRange, except: usize) -> (i64, i64) {
+        let (mut lo, mut hi) = (0, 0);
+        for (i, &c) in self.coefficients.iter().enumerate().filter(|&(i, _)| i != except) {
+            let (a, b) = (c * range.min[i] as i64, c * range.max[i] as i64);
+            lo += a.min(b);
+            hi += a.max(b);
+        }
+        (lo, hi)
+    }
+
+    // Attribute, split value, and whether parts below the split are the ones that hold - if only
+    // one attribute is used
+    fn axis(&self) -> Option<(usize, i64, bool)> {
+        let mut used = self.coefficients.iter().enumerate().filter(|&(_, &c)| c != 0);
+        let (var, &c) = used.next()?;
+        if used.next().is_some() {
+            return None;
+        }
+        Some(if c > 0 {
+            (var, self.bound.div_euclid(c) + 1, true)
+        } else {
+            (var, -self.bound.div_euclid(-c), false)
+        })
+    }
+}
+
+// Sum of terms, each a number, attribute, or number times attribute, eg. "x+2*m-10"
+#[derive(Debug, Clone)]
+struct Linear {
+    coefficients: Vec<i64>,
+    constant: i64,
+}
+
+impl Linear {
+    fn from_string(s: &str, schema: &Schema) -> Result<Self, ParseError> {
+        if s.trim().is_empty() {
+            return Err(ParseError::new("Expected: number or attribute"));
+        }
+        let mut r = Linear { coefficients: vec![0; schema.names.len()], constant: 0 };
+
+        // Each term starts with its sign, if any
+        let starts = s.char_indices().filter(|&(i, c)| i == 0 || c == '+' || c == '-').map(|(i, _)| i);
+        let ends = starts.clone().skip(1).chain([s.len()]);
+        for (start, end) in starts.zip(ends) {
+            let term = &s[start..end];
+            let (sign, body) = match term.strip_prefix('-') {
+                Some(body) => (-1, body),
+                None => (1, term.strip_prefix('+').unwrap_or(term)),
+            };
+            let mut factor: i64 = sign;
+            let mut var = None;
+            for f in body.split('*') {
+                if f.trim().starts_with(|c: char| c.is_ascii_alphabetic()) {
+                    if var.is_some() {
+                        return Err(ParseError::new("Only one attribute per term").within(s, f));
+                    }
+                    var = Some(schema.variable_to_index(f.trim()).map_err(|e| e.within(s, f))?);
+                } else {
+                    let n = parse_value::<i64>(f).map_err(|e| e.within(s, f))?;
+                    factor = factor.checked_mul(n).ok_or_else(|| ParseError::new("Number too large").within(s, f))?;
+                }
+            }
+            // Attribute terms must fit at any value in the domain
+            let largest = match var {
+                Some(_) => i64::try_from(*schema.domain.end()).ok().and_then(|max| factor.checked_mul(max)),
+                None => Some(factor),
+            };
+            let total = match var {
+                Some(v) => &mut r.coefficients[v],
+                None => &mut r.constant,
+            };
+            *total = largest.and_then(|_| total.checked_add(factor)).ok_or_else(|| ParseError::new("Number too large").within(s, term))?;
+        }
+        Ok(r)
+    }
+
+    // self - other <= bound - None if sums over the domain might not fit in an i64
+    fn at_most(&self, other: &Linear, bound: i64, schema: &Schema) -> Option<Constraint> {
+        let coefficients = self.coefficients.iter().zip(&other.coefficients).map(|(a, b)| a.checked_sub(*b)).collect::<Option<Vec<_>>>()?;
+        let bound = bound.checked_sub(self.constant)?.checked_add(other.constant)?;
+
+        // Largest the bound and the sum can be together, with room to negate and step past them
+        let max = i64::try_from(*schema.domain.end()).ok()?;
+        let largest = coefficients.iter().try_fold(bound.checked_abs()?.checked_add(1)?, |total, c| total.checked_add(c.checked_abs()?.checked_mul(max)?))?;
+        largest.checked_mul(2)?;
+
+        Some(Constraint { coefficients, bound })
+    }
+}
+
+// Rule condition - holds if all the constraints in any one list hold
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Condition {
+    pub any: Vec<Vec<Constraint>>,
+}
+
+impl Condition {
+    // Comparisons of sums of attributes, joined with && or ||, eg. "x+m>3000", "a<=5&&s!=7||x==m"
+    fn from_string(s: &str, schema: &Schema) -> Result<Self, ParseError> {
+        let mut any = Vec::new();
+        for alternative in s.split("||") {
+            // Expand each && of lists into every combination
+            let mut all = vec![Vec::new()];
+            for comparison in alternative.split("&&") {
+                let c = Self::comparison(comparison, schema).map_err(|e| e.within(s, comparison))?;
+                all = all.iter().flat_map(|a| c.iter().map(move |b| [a.clone(), b.clone()].concat())).collect();
+            }
+            any.extend(all);
+        }
+        Ok(Condition { any })
+    }
+
+    // eg. "x+m>3000" - != becomes either < or >
+    fn comparison(s: &str, schema: &Schema) -> Result<Vec<Vec<Constraint>>, ParseError> {
+        let Some(i) = s.find(['<', '>', '=', '!']) else {
+            return Err(ParseError::new(format!("Bad condition: {s}")));
+        };
+        let op = if s[i + 1..].starts_with('=') { &s[i..i + 2] } else { &s[i..i + 1] };
+        let (left, right) = (&s[..i], &s[i + op.len()..]);
+        let l = Linear::from_string(left, schema).map_err(|e| e.within(s, left).with_column(1))?;
+        let r = Linear::from_string(right, schema).map_err(|e| e.within(s, right).with_column(i + op.len() + 1))?;
+        let at_most = |a: &Linear, b: &Linear, bound| a.at_most(b, bound, schema).ok_or_else(|| ParseError::new("Numbers too large to compare"));
+        Ok(match op {
+            "<" => vec![vec![at_most(&l, &r, -1)?]],
+            "<=" => vec![vec![at_most(&l, &r, 0)?]],
+            ">" => vec![vec![at_most(&r, &l, -1)?]],
+            ">=" => vec![vec![at_most(&r, &l, 0)?]],
+            "==" => vec![vec![at_most(&l, &r, 0)?, at_most(&r, &l, 0)?]],
+            "!=" => vec![vec![at_most(&l, &r, -1)?], vec![at_most(&r, &l, -1)?]],
+            _ => return Err(ParseError::new(format!("Bad op: {op}")).with_column(i + 1)),
+        })
+    }
+
+    pub fn holds(&self, part: &Part) -> bool {
+        self.any.iter().any(|all| all.iter().all(|c| c.holds(part)))
+    }
+}
+
+// Parts in a box that also meet some constraints - which can cut across the box at any angle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub range: PartRange,
+    pub constraints: Vec<Constraint>,
+}
+
+// Rounds of narrowing a region's box to fit its constraints - this only has to be good enough
+// to catch most empty regions, as counting is exact anyway
+const TIGHTEN_ROUNDS: usize = 8;
+
+// Most sums to keep counts for when counting a constraint on several attributes - past this the
+// region is halved, and each half counted
+const SUM_TABLE_LIMIT: i64 = 1 << 20;
+
+impl Region {
+    pub fn new(range: PartRange) -> Self {
+        Region { range, constraints: Vec::new() }
+    }
+
+    // Parts in this region that also meet 'constraint' - None if there obviously are none
+    pub fn with(&self, constraint: &Constraint) -> Option<Region> {
+        let mut r = self.clone();
+        r.constraints.push(constraint.clone());
+        r.tighten().then_some(r)
+    }
+
+    // Disjoint pieces of the region where condition holds, and where it doesn't
+    pub fn split(&self, condition: &Condition) -> (Vec<Region>, Vec<Region>) {
+        let (mut passed, mut failed) = (Vec::new(), vec![self.clone()]);
+        for all in &condition.any {
+            let mut still_failed = Vec::new();
+            for region in failed {
+                // Meets every constraint in the list, or fails at one of them
+                let mut r = Some(region);
+                for c in all {
+                    let Some(current) = r else { break };
+                    still_failed.extend(current.with(&c.negated()));
+                    r = current.with(c);
+                }
+                passed.extend(r);
+            }
+            failed = still_failed;
+        }
+        (passed, failed)
+    }
+
+    // Shrink box to what each constraint allows given the other attributes, then drop constraints
+    // that hold across the whole box - false if the region is empty
+    fn tighten(&mut self) -> bool {
+        if (0..self.range.min.len()).any(|i| self.range.min[i] > self.range.max[i]) {
+            return false;
+        }
+        for _ in 0..TIGHTEN_ROUNDS {
+            let mut changed = false;
+            for c in &self.constraints {
+                for (j, &cj) in c.coefficients.iter().enumerate().filter(|&(_, &cj)| cj != 0) {
+                    // cj * vars[j] <= limit
+                    let limit = c.bound - c.limits(&self.range, j).0;
+                    let (min, max) = (self.range.min[j] as i64, self.range.max[j] as i64);
+                    let (lo, hi) = if cj > 0 { (min, max.min(limit.div_euclid(cj))) } else { (min.max(-limit.div_euclid(-cj)), max) };
+                    if lo > hi {
+                        return false;
+                    }
+                    if (lo, hi) != (min, max) {
+                        (self.range.min[j], self.range.max[j]) = (lo as usize, hi as usize);
+                        changed = true;
+                    }
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let range = &self.range;
+        self.constraints.retain(|c| c.limits(range, usize::MAX).1 > c.bound);
+        self.constraints.iter().all(|c| c.limits(range, usize::MAX).0 <= c.bound)
+    }
+
+    // Widest attribute that any remaining constraint uses
+    fn widest(&self) -> usize {
+        (0..self.range.min.len())
+            .filter(|&i| self.constraints.iter().any(|c| c.coefficients[i] != 0))
+            .max_by_key(|&i| self.range.max[i] - self.range.min[i])
+            .unwrap()
+    }
+
+    // Region split across attribute 'var' - each half is tightened, and None if it is empty
+    fn halves(&self, var: usize) -> [Option<Region>; 2] {
+        let mid = (self.range.min[var] + self.range.max[var]) / 2;
+        let mut below = self.clone();
+        let mut above = self.clone();
+        below.range.max[var] = mid;
+        above.range.min[var] = mid + 1;
+        [below, above].map(|mut half| half.tighten().then_some(half))
+    }
+
+    // Cut the region into boxes that lie entirely inside it - halving until no constraints are left
+    pub fn for_each_box(&self, f: &mut impl FnMut(&PartRange)) {
+        if self.constraints.is_empty() {
+            f(&self.range);
+            return;
+        }
+        for half in self.halves(self.widest()).into_iter().flatten() {
+            half.for_each_box(f);
+        }
+    }
+
+    // Counted a column at a time once only one constraint, on two attributes, is left, or from a
+    // table of sums for more attributes - otherwise several constraints are halved until only
+    // one is left. None if there are too many to count.
+    pub fn combinations(&self) -> Option<usize> {
+        let range = &self.range;
+        match &self.constraints[..] {
+            [] => range.combinations(),
+            [c] => {
+                let free = (0..range.min.len()).filter(|&i| c.coefficients[i] != 0 && range.min[i] < range.max[i]);
+                if free.clone().count() == 2 {
+                    let narrowest = free.min_by_key(|&i| range.max[i] - range.min[i]).unwrap();
+                    return self.column_combinations(c, narrowest);
+                }
+                self.sum_combinations(c)
+            }
+            _ => self.halved_combinations(self.widest()),
+        }
+    }
+
+    fn halved_combinations(&self, var: usize) -> Option<usize> {
+        let mut c: usize = 0;
+        for half in self.halves(var).into_iter().flatten() {
+            c = c.checked_add(half.combinations()?)?;
+        }
+        Some(c)
+    }
+
+    // Only constraint is 'c' - count how many ways the attributes it uses can add up to at most
+    // its bound. Each attribute counts up from its lowest term, so the sum is of weight * steps,
+    // with 0 <= steps <= width. All but the largest term go in a table of how many ways each sum
+    // can be made, then the largest is counted a column at a time against that.
+    fn sum_combinations(&self, c: &Constraint) -> Option<usize> {
+        let range = &self.range;
+        let limit = c.bound - c.limits(range, usize::MAX).0;
+        if limit < 0 {
+            return Some(0);
+        }
+        let mut terms = (0..range.min.len())
+            .filter(|&i| c.coefficients[i] != 0 && range.min[i] < range.max[i])
+            .map(|i| (c.coefficients[i].abs(), (range.max[i] - range.min[i]) as i64, i))
+            .collect::<Vec<_>>();
+        terms.sort_by_key(|&(weight, width, _)| weight * width);
+        // Attributes the constraint doesn't depend on can take any value
+        let mut others = (0..range.min.len())
+            .filter(|&i| c.coefficients[i] == 0 || range.min[i] == range.max[i])
+            .map(|i| range.max[i] + 1 - range.min[i]);
+        let others = others.try_fold(1usize, |product, n| product.checked_mul(n))?;
+        let Some((weight, width, _)) = terms.pop() else {
+            return Some(others);
+        };
+
+        // Sums above the limit are never needed - if there are still too many, halve the
+        // attribute that adds most to them
+        let size = limit.min(terms.iter().map(|&(weight, width, _)| weight * width).sum()) + 1;
+        if size > SUM_TABLE_LIMIT {
+            return self.halved_combinations(terms.last().unwrap().2);
+        }
+        let mut ways = vec![0u128; size as usize];
+        let mut next = ways.clone();
+        ways[0] = 1;
+        for &(weight, width, _) in &terms {
+            // Each sum s can be reached from s - weight * k, for k in 0..=width - a sliding window
+            // over the sums in steps of weight
+            let (weight, span) = (weight as usize, (weight * (width + 1)) as usize);
+            for s in 0..ways.len() {
+                let window = if s >= weight { next[s - weight] } else { 0 };
+                let gone = if s >= span { ways[s - span] } else { 0 };
+                next[s] = window.checked_add(ways[s])? - gone;
+            }
+            std::mem::swap(&mut ways, &mut next);
+        }
+        // Ways to make each sum or less
+        for s in 1..ways.len() {
+            ways[s] = ways[s].checked_add(ways[s - 1])?;
+        }
+
+        let mut count: u128 = 0;
+        for steps in 0..=width.min(limit / weight) {
+            let rest = (limit - weight * steps).min(size - 1);
+            count = count.checked_add(ways[rest as usize])?;
+        }
+        usize::try_from(count).ok()?.checked_mul(others)
+    }
+
+    // Only constraint uses 'var' and one other attribute 'other' - for each value of var, count
+    // the values of other that meet it
+    fn column_combinations(&self, c: &Constraint, var: usize) -> Option<usize> {
+        let range = &self.range;
+        let other = (0..range.min.len()).find(|&i| i != var && c.coefficients[i] != 0 && range.min[i] < range.max[i]).unwrap();
+        let (cv, co) = (c.coefficients[var], c.coefficients[other]);
+        let (min, max) = (range.min[other] as i64, range.max[other] as i64);
+        // Any other attributes the constraint uses are fixed
+        let fixed = (0..range.min.len()).filter(|&i| i != var && i != other).map(|i| c.coefficients[i] * range.min[i] as i64);
+        let rest = c.bound - fixed.sum::<i64>();
+        let mut column: usize = 0;
+        for v in range.min[var]..=range.max[var] {
+            // co * other <= limit
+            let limit = rest - cv * v as i64;
+            let (lo, hi) = if co > 0 { (min, max.min(limit.div_euclid(co))) } else { (min.max(-limit.div_euclid(-co)), max) };
+            column = column.checked_add((hi - lo + 1).max(0) as usize)?;
+        }
+        let mut others = (0..range.min.len()).filter(|&i| i != var && i != other).map(|i| range.max[i] + 1 - range.min[i]);
+        others.try_fold(column, |c, n| c.checked_mul(n))
+    }
+}
+
+#[derive(Debug)]
+pub struct Process {
+    schema: Schema,
+    steps: Vec<(Operation, usize)>,
+    conditions: Vec<Condition>,
+    // Step each workflow starts at, and its name - in step order
+    workflows: Vec<(usize, String)>,
+    entry: usize
+}
+
+impl Process {
+    pub fn assemble(source: &[&str], schema: Schema) -> Result<Self, ParseError> {
+        let mut steps = Vec::new();
+        let mut conditions = Vec::new();
+        let mut symbols : HashMap<_, usize> = HashMap::new();
+        let mut workflows = Vec::new();
+
+        symbols.insert("A".to_string(), 0);
+        symbols.insert("R".to_string(), 1);
+
+        // Two passes to resolve forward refs.
+        for pass in 0..2 {
+            // Rest output
+            steps = vec![(Operation::Accept,0),(Operation::Reject,0)];
+            conditions.clear();
+            workflows.clear();
+            for (i, rl) in source.iter().enumerate() {
+                let Some((name, rules)) = rl.strip_suffix('}').and_then(|r| r.split_once('{')) else {
+                    return Err(ParseError::at(i + 1, 0, "Expected: name{rules}"));
+                };
+                let rules = rules.split_terminator(',').collect::<Vec<_>>();
+
+                // A second definition would quietly replace the first
+                if pass == 0 && symbols.contains_key(name) {
+                    let first = source[..i].iter().position(|l| l.split_once('{').is_some_and(|(n, _)| n == name));
+                    let message = match first {
+                        Some(first) => format!("Workflow {name} already defined on line {}", first + 1),
+                        None => format!("Reserved workflow name: {name}"),
+                    };
+                    return Err(ParseError::new(message).within(rl, name).with_line(i + 1));
+                }
+                // Parts would run on into the next workflow's rules
+                match rules.last() {
+                    None => return Err(ParseError::new(format!("Workflow has no rules: {name}")).within(rl, name).with_line(i + 1)),
+                    Some(last) if last.contains(':') => return Err(ParseError::new("Last rule must always apply").within(rl, last).with_line(i + 1)),
+                    _ => ()
+                }
+                symbols.insert(name.to_string(), steps.len());
+                workflows.push((steps.len(), name.to_string()));
+                for rule in rules {
+                    let (op,sym) = if let Some((cond, sym)) = rule.split_once(':') {
+                        let op = match Operation::from_string(cond, &schema) {
+                            Ok(op) => op,
+                            // Anything more than a single < or >
+                            Err(_) => {
+                                let condition = Condition::from_string(cond, &schema).map_err(|e| e.within(rl, cond).with_line(i + 1))?;
+                                conditions.push(condition);
+                                Operation::Test(conditions.len() - 1)
+                            }
+                        };
+                        (op, sym)
+                    } else {
+                        (Operation::Always, rule)
+                    };
+                    let next = *symbols.get(sym).unwrap_or(&usize::MAX);
+                    if pass == 1 && next == usize::MAX {
+                        return Err(ParseError::new(format!("Unknown workflow: {sym}")).within(rl, sym).with_line(i + 1));
+                    }
+                    steps.push((op, next));
+                }
+            }
+
+        }
+        // Lookup entry point
+        let entry = *symbols.get("in").ok_or(ParseError::new("No 'in' workflow"))?;
+
+        Ok(Process { schema, steps, conditions, workflows, entry })
+     }
+
+    // Interpret the steps for a range of parts, adding up the accepted combinations - None if there
+    // are too many to count
+    pub fn add_combinations(&self, entry: usize, range: PartRange, combinations: usize) -> Option<usize> {
+        self.add_region_combinations(entry, Region::new(range), combinations)
+    }
+
+    fn add_region_combinations(&self, entry: usize, region: Region, combinations: usize) -> Option<usize> {
+        let mut step = entry;
+        let range = &region.range;
+
+        loop {
+            let (op, next) = &self.steps[step];
+            step = match op {
+                Operation::Less(var, value) =>
+                    if range.min[*var] < *value {
+                        if range.max[*var] >= *value {
+                            // Range covers decision value - sum both branches with reduced range
+                            let mut region_in = region.clone();
+                            let mut region_out = region.clone();
+                            region_in.range.max[*var] = *value-1;
+                            region_out.range.min[*var] = *value;
+                            let c = self.add_region_combinations(*next, region_in, combinations)?;
+                            return self.add_region_combinations(step+1, region_out, c); // tail recursion
+                        } else {
+                            *next
+                        }
+                    } else {
+                        step+1
+                    }
+
+                Operation::Greater(var, value) =>
+                    if range.max[*var] > *value {
+                        if range.min[*var]  <= *value {
+                            // Range covers decision value - sum both branches with reduced range
+                            let mut region_in = region.clone();
+                            let mut region_out = region.clone();
+                            region_in.range.min[*var] = *value+1;
+                            region_out.range.max[*var] = *value;
+                            let c = self.add_region_combinations(*next, region_in, combinations)?;
+                            return self.add_region_combinations(step+1, region_out, c); // tail recursion
+                        } else {
+                            *next
+                        }
+                    } else {
+                        step+1
+                    }
+
+                Operation::Test(condition) => return self.add_test_combinations(step, *condition, (0, 0), &region, combinations),
+                Operation::Always => *next,
+                Operation::Reject => return Some(combinations),
+                Operation::Accept => return combinations.checked_add(region.combinations()?)
+            }
+        }
+    }
+
+    // Parts in region that have passed the first 'term' constraints in list 'any' of the condition
+    // at 'step' - split on each constraint in turn, going on to the next list if one fails
+    fn add_test_combinations(&self, step: usize, condition: usize, (any, term): (usize, usize), region: &Region, combinations: usize) -> Option<usize> {
+        let lists = &self.conditions[condition].any;
+        if any == lists.len() {
+            return self.add_region_combinations(step + 1, region.clone(), combinations);
+        }
+        if term == lists[any].len() {
+            return self.add_region_combinations(self.steps[step].1, region.clone(), combinations);
+        }
+        let constraint = &lists[any][term];
+        let mut c = combinations;
+        if let Some(passed) = region.with(constraint) {
+            c = self.add_test_combinations(step, condition, (any, term + 1), &passed, c)?;
+        }
+        if let Some(failed) = region.with(&constraint.negated()) {
+            c = self.add_test_combinations(step, condition, (any + 1, 0), &failed, c)?;
+        }
+        Some(c)
+    }
+
+    // Workflow that step is in - the last one starting at or before it
+    fn workflow_at(&self, step: usize) -> usize {
+        self.workflows.partition_point(|&(start, _)| start <= step) - 1
+    }
+
+    // Loop of workflows that parts from 'in' would go round forever - loops that only empty ranges
+    // of parts could reach don't matter. Reported at the rule that closes the loop.
+    fn find_loop(&self, source: &[&str]) -> Option<ParseError> {
+        let entry = self.workflow_at(self.entry);
+        let (path, step) = self.find_region_loop(self.entry, Region::new(PartRange::full_range(&self.schema)), &mut vec![entry])?;
+
+        let names = path.iter().map(|&w| self.workflows[w].1.as_str()).collect::<Vec<_>>();
+        let w = self.workflow_at(step);
+        let text = source[w];
+        let rule = text.strip_suffix('}').and_then(|r| r.split_once('{'))?.1.split_terminator(',').nth(step - self.workflows[w].0)?;
+        let sym = rule.split_once(':').map_or(rule, |(_, sym)| sym);
+        Some(ParseError::new(format!("Workflow cycle: {}", names.join(" -> "))).within(text, sym).with_line(w + 1))
+    }
+
+    // As add_region_combinations, keeping the workflows on the way to 'step' - the loop, and the
+    // step that goes back round it, if region gets to one of them again
+    fn find_region_loop(&self, step: usize, region: Region, path: &mut Vec<usize>) -> Option<(Vec<usize>, usize)> {
+        // Region with attribute 'var' limited to min..=max - None if nothing is left
+        let clipped = |var: usize, min: usize, max: usize| {
+            let mut r = region.clone();
+            r.range.min[var] = r.range.min[var].max(min);
+            r.range.max[var] = r.range.max[var].min(max);
+            (r.range.min[var] <= r.range.max[var]).then_some(r)
+        };
+
+        // Parts that take the rule, and parts that go on to the next one
+        let (op, next) = self.steps[step];
+        let (taken, rest) = match op {
+            Operation::Less(var, value) =>
+                (Vec::from_iter(value.checked_sub(1).and_then(|v| clipped(var, 0, v))), Vec::from_iter(clipped(var, value, usize::MAX))),
+            Operation::Greater(var, value) =>
+                (Vec::from_iter(value.checked_add(1).and_then(|v| clipped(var, v, usize::MAX))), Vec::from_iter(clipped(var, 0, value))),
+            Operation::Test(condition) => region.split(&self.conditions[condition]),
+            Operation::Always => (vec![region], Vec::new()),
+            Operation::Accept | Operation::Reject => return None,
+        };
+
+        // Steps before the first workflow are A and R
+        if !taken.is_empty() && next >= self.workflows[0].0 {
+            let w = self.workflow_at(next);
+            if let Some(start) = path.iter().position(|&p| p == w) {
+                return Some((path[start..].iter().copied().chain([w]).collect(), step));
+            }
+            path.push(w);
+            for r in taken {
+                if let Some(found) = self.find_region_loop(next, r, path) {
+                    return Some(found);
+                }
+            }
+            path.pop();
+        }
+        rest.into_iter().find_map(|r| self.find_region_loop(step + 1, r, path))
+    }
+
+    // Run one part through the workflows, calling 'taken' with each step that sends it on
+    fn run(&self, part: &Part, mut taken: impl FnMut(usize)) -> bool {
+        let mut step = self.entry;
+        loop {
+            let (op, next) = self.steps[step];
+            let matched = match op {
+                Operation::Less(var, value) => part.vars[var] < value,
+                Operation::Greater(var, value) => part.vars[var] > value,
+                Operation::Test(condition) => self.conditions[condition].holds(part),
+                Operation::Always => true,
+                Operation::Reject => return false,
+                Operation::Accept => return true
+            };
+            if matched {
+                taken(step);
+                step = next;
+            } else {
+                step += 1;
+            }
+        }
+    }
+
+    pub fn accepts(&self, part: &Part) -> bool {
+        self.run(part, |_| ())
+    }
+
+    // Workflows the part goes through, with the rule in each that sends it on
+    pub fn explain(&self, part: &Part) -> Explanation {
+        let mut path = Vec::new();
+        let accepted = self.run(part, |step| path.push(step));
+        let path = path.into_iter().map(|step| {
+            let (start, name) = &self.workflows[self.workflow_at(step)];
+            (name.clone(), step - start)
+        });
+        Explanation { path: path.collect(), accepted }
+    }
+
+    pub fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    pub fn entry(&self) -> usize {
+        self.entry
+    }
+
+    pub fn compile(&self) -> DecisionTree {
+        DecisionTree::compile(self)
+    }
+}
+
+// Path of a part through the workflows - eg. "in[1] -> qqz[0] -> qs[1] -> lnx[0] -> A"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    // Workflow name, and index of the rule that matched
+    pub path: Vec<(String, usize)>,
+    pub accepted: bool,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (name, rule) in &self.path {
+            write!(f, "{name}[{rule}] -> ")?;
+        }
+        write!(f, "{}", if self.accepted { "A" } else { "R" })
+    }
+}
+
+// Rules, then a blank line, then parts - with attribute values from the puzzle
+pub fn parse(input: &str) -> Result<(Process, Vec<Part>), ParseError> {
+    parse_with_domain(input, PUZZLE_DOMAIN)
+}
+
+pub fn parse_with_domain(input: &str, domain: RangeInclusive<usize>) -> Result<(Process, Vec<Part>), ParseError> {
+    let mut lines = input.lines();
+    let rule_lines = lines.by_ref().take_while(|l| !l.is_empty()).collect::<Vec<_>>();
+    let part_lines = lines.take_while(|l| !l.is_empty()).collect::<Vec<_>>();
+
+    let schema = Schema::infer(&rule_lines, &part_lines, domain);
+    let process = Process::assemble(&rule_lines, schema)?;
+    // Parts would go round a loop forever
+    if let Some(cycle) = process.find_loop(&rule_lines) {
+        return Err(cycle);
+    }
+
+    // Parts start after rules and the blank line
+    let mut parts = Vec::new();
+    for (i, l) in part_lines.iter().enumerate() {
+        parts.push(Part::from_string(l, &process.schema).map_err(|e| e.with_line(rule_lines.len() + i + 2))?);
+    }
+
+    Ok((process, parts))
+}
+
+// Workflow as written - for checking workflows against each other before they are assembled
+struct Workflow<'a> {
+    line: usize,
+    text: &'a str,
+    name: &'a str,
+    // Condition (None for a rule that always applies), rule, and workflow it sends parts to
+    rules: Vec<(Option<Condition>, &'a str, &'a str)>,
+}
+
+fn workflows<'a>(rule_lines: &[&'a str], schema: &Schema) -> Result<Vec<Workflow<'a>>, ParseError> {
+    let mut workflows = Vec::new();
+    for (i, &text) in rule_lines.iter().enumerate() {
+        let Some((name, rules)) = text.strip_suffix('}').and_then(|r| r.split_once('{')) else {
+            return Err(ParseError::at(i + 1, 0, "Expected: name{rules}"));
+        };
+        let mut workflow = Workflow { line: i + 1, text, name, rules: Vec::new() };
+        for rule in rules.split_terminator(',') {
+            workflow.rules.push(match rule.split_once(':') {
+                Some((cond, sym)) => {
+                    let condition = Condition::from_string(cond, schema).map_err(|e| e.within(text, cond).with_line(i + 1))?;
+                    (Some(condition), rule, sym)
+                }
+                None => (None, rule, rule),
+            });
+        }
+        workflows.push(workflow);
+    }
+    Ok(workflows)
+}
+
+// Each way round a loop of workflows, at the rule that closes it
+fn cycles(workflows: &[Workflow]) -> Vec<ParseError> {
+    let index = workflows.iter().enumerate().rev().map(|(i, w)| (w.name, i)).collect::<HashMap<_, _>>();
+
+    // Depth first, keeping the path to the current workflow - any rule back to a workflow on
+    // the path closes a loop
+    fn visit(workflows: &[Workflow], index: &HashMap<&str, usize>, w: usize, path: &mut Vec<usize>, done: &mut [bool], found: &mut Vec<ParseError>) {
+        path.push(w);
+        for &(_, _, sym) in &workflows[w].rules {
+            let Some(&next) = index.get(sym) else { continue };
+            if let Some(start) = path.iter().position(|&p| p == next) {
+                let names = path[start..].iter().chain([&next]).map(|&p| workflows[p].name).collect::<Vec<_>>();
+                let text = workflows[w].text;
+                found.push(ParseError::new(format!("Workflow cycle: {}", names.join(" -> "))).within(text, sym).with_line(workflows[w].line));
+            } else if !done[next] {
+                visit(workflows, index, next, path, done, found);
+            }
+        }
+        path.pop();
+        done[w] = true;
+    }
+
+    let mut done = vec![false; workflows.len()];
+    let mut found = Vec::new();
+    for w in 0..workflows.len() {
+        if !done[w] {
+            visit(workflows, &index, w, &mut Vec::new(), &mut done, &mut found);
+        }
+    }
+    found
+}
+
+// Check rules from the input for problems - unknown or unused workflows, loops, rules that never
+// apply, and workflows that never accept a part. Only errors in the rules themselves fail.
+pub fn validate(input: &str) -> Result<Vec<ParseError>, ParseError> {
+    validate_with_domain(input, PUZZLE_DOMAIN)
+}
+
+pub fn validate_with_domain(input: &str, domain: RangeInclusive<usize>) -> Result<Vec<ParseError>, ParseError> {
+    let mut lines = input.lines();
+    let rule_lines = lines.by_ref().take_while(|l| !l.is_empty()).collect::<Vec<_>>();
+    let part_lines = lines.take_while(|l| !l.is_empty()).collect::<Vec<_>>();
+    let schema = Schema::infer(&rule_lines, &part_lines, domain);
+    let workflows = workflows(&rule_lines, &schema)?;
+
+    let mut found = Vec::new();
+    let mut index = HashMap::new();
+    for (i, w) in workflows.iter().enumerate() {
+        if w.name == "A" || w.name == "R" {
+            found.push(ParseError::new(format!("Reserved workflow name: {}", w.name)).within(w.text, w.name).with_line(w.line));
+        } else if let Some(&first) = index.get(w.name) {
+            let first: &Workflow = &workflows[first];
+            found.push(ParseError::new(format!("Workflow {} already defined on line {}", w.name, first.line)).within(w.text, w.name).with_line(w.line));
+        } else {
+            index.insert(w.name, i);
+        }
+        match w.rules.last() {
+            None => found.push(ParseError::new(format!("Workflow has no rules: {}", w.name)).within(w.text, w.name).with_line(w.line)),
+            Some(&(Some(_), rule, _)) => found.push(ParseError::new("Last rule must always apply").within(w.text, rule).with_line(w.line)),
+            _ => ()
+        }
+    }
+    for w in &workflows {
+        for &(_, _, sym) in w.rules.iter().filter(|&&(_, _, sym)| sym != "A" && sym != "R" && !index.contains_key(sym)) {
+            found.push(ParseError::new(format!("Unknown workflow: {sym}")).within(w.text, sym).with_line(w.line));
+        }
+    }
+    found.extend(cycles(&workflows));
+
+    // Workflows 'in' can lead to
+    match index.get("in") {
+        None => found.push(ParseError::new("No 'in' workflow")),
+        Some(&entry) => {
+            let mut used = vec![false; workflows.len()];
+            let mut pending = vec![entry];
+            while let Some(w) = pending.pop() {
+                if !std::mem::replace(&mut used[w], true) {
+                    pending.extend(workflows[w].rules.iter().filter_map(|&(_, _, sym)| index.get(sym)));
+                }
+            }
+            // Second definitions, and reserved names, are reported already
+            for (_, w) in workflows.iter().enumerate().filter(|&(i, w)| !used[i] && index.get(w.name) == Some(&i)) {
+                found.push(ParseError::new(format!("Unused workflow: {}", w.name)).within(w.text, w.name).with_line(w.line));
+            }
+        }
+    }
+
+    // Follow the parts left over by each rule, to find rules that get none, or none they match
+    let full_range = PartRange::full_range(&schema);
+    let mut live = Vec::new();
+    for w in &workflows {
+        let mut left = vec![Region::new(full_range.clone())];
+        let mut rules = Vec::new();
+        for (r, (condition, rule, _)) in w.rules.iter().enumerate() {
+            let (matched, rest) = match condition {
+                Some(condition) => left.iter().map(|region| region.split(condition)).fold((Vec::new(), Vec::new()), |(mut m, mut l), (a, b)| {
+                    m.extend(a);
+                    l.extend(b);
+                    (m, l)
+                }),
+                None => (left, Vec::new()),
+            };
+            // Too many to count is still some
+            let matches = matched.iter().any(|region| region.combinations() != Some(0));
+            if !matches {
+                let message = if r == 0 { "Rule never matches" } else { "Rule never matches - earlier rules take every part it would" };
+                found.push(ParseError::new(message).within(w.text, rule).with_line(w.line));
+            }
+            rules.push(matches);
+            left = rest;
+        }
+        live.push(rules);
+    }
+
+    // Workflows with a rule that matches something and leads to A - directly, or through others
+    let mut accepts = vec![false; workflows.len()];
+    loop {
+        let mut changed = false;
+        for (i, w) in workflows.iter().enumerate() {
+            if !accepts[i] && w.rules.iter().zip(&live[i]).any(|(&(_, _, sym), &live)| live && (sym == "A" || index.get(sym).is_some_and(|&n| accepts[n]))) {
+                accepts[i] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    for (_, w) in workflows.iter().enumerate().filter(|&(i, w)| !accepts[i] && index.get(w.name) == Some(&i)) {
+        found.push(ParseError::new(format!("Workflow never accepts: {}", w.name)).within(w.text, w.name).with_line(w.line));
+    }
+
+    found.sort_by_key(|e| (e.line, e.column));
+    Ok(found)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node {
+    Accept,
+    Reject,
+    // Parts with vars[var] < value go to 'below', the rest to 'above'
+    Split { var: usize, value: usize, below: usize, above: usize },
+    // Parts that meet a constraint on more than one attribute go to 'yes', the rest to 'no'
+    Test { constraint: usize, yes: usize, no: usize },
+}
+
+const ACCEPT: usize = 0;
+const REJECT: usize = 1;
+
+// Workflows compiled to a decision diagram - each split is only made where both sides can be
+// reached, and identical subtrees are shared
+#[derive(Debug, Clone)]
+pub struct DecisionTree {
+    nodes: Vec<Node>,
+    constraints: Vec<Constraint>,
+    root: usize,
+    full_range: PartRange,
+}
+
+// Nodes and constraints already in the tree
+#[derive(Default)]
+struct Unique {
+    nodes: HashMap<Node, usize>,
+    constraints: HashMap<Constraint, usize>,
+}
+
+impl DecisionTree {
+    fn compile(process: &Process) -> Self {
+        let full_range = PartRange::full_range(&process.schema);
+        let mut tree = DecisionTree { nodes: vec![Node::Accept, Node::Reject], constraints: Vec::new(), root: ACCEPT, full_range: full_range.clone() };
+        let mut unique = Unique::default();
+        tree.root = tree.compile_step(process, process.entry, Region::new(full_range), &mut unique);
+        tree
+    }
+
+    // Node for parts in 'region' arriving at 'step' - rules the region is entirely on one side of
+    // are followed without a split
+    fn compile_step(&mut self, process: &Process, mut step: usize, region: Region, unique: &mut Unique) -> usize {
+        loop {
+            let (op, next) = process.steps[step];
+            // Where the rule splits, and the steps for parts below and above the split
+            let (var, value, below, above) = match op {
+                Operation::Less(var, value) => (var, value, next, step + 1),
+                Operation::Greater(var, value) => match value.checked_add(1) {
+                    Some(value) => (var, value, step + 1, next),
+                    // Nothing is greater
+                    None => {
+                        step += 1;
+                        continue;
+                    }
+                },
+                Operation::Test(condition) => return self.compile_test(process, step, condition, (0, 0), region, unique),
+                Operation::Always => {
+                    step = next;
+                    continue;
+                }
+                Operation::Accept => return ACCEPT,
+                Operation::Reject => return REJECT,
+            };
+
+            if region.range.max[var] < value {
+                step = below;
+            } else if region.range.min[var] >= value {
+                step = above;
+            } else {
+                let mut region_below = region.clone();
+                let mut region_above = region;
+                region_below.range.max[var] = value - 1;
+                region_above.range.min[var] = value;
+                // Other constraints may leave nothing on one side
+                match (region_below.tighten(), region_above.tighten()) {
+                    (true, false) => return self.compile_step(process, below, region_below, unique),
+                    (false, true) => return self.compile_step(process, above, region_above, unique),
+                    _ => (),
+                }
+                let below = self.compile_step(process, below, region_below, unique);
+                let above = self.compile_step(process, above, region_above, unique);
+                return self.split(var, value, below, above, unique);
+            }
+        }
+    }
+
+    // As Process::add_test_combinations - parts in region that have passed the first 'term'
+    // constraints in list 'any' of a condition
+    fn compile_test(&mut self, process: &Process, step: usize, condition: usize, (any, term): (usize, usize), region: Region, unique: &mut Unique) -> usize {
+        let lists = &process.conditions[condition].any;
+        if any == lists.len() {
+            return self.compile_step(process, step + 1, region, unique);
+        }
+        if term == lists[any].len() {
+            return self.compile_step(process, process.steps[step].1, region, unique);
+        }
+        let constraint = &lists[any][term];
+        match (region.with(constraint), region.with(&constraint.negated())) {
+            (Some(passed), None) => self.compile_test(process, step, condition, (any, term + 1), passed, unique),
+            (None, Some(failed)) => self.compile_test(process, step, condition, (any + 1, 0), failed, unique),
+            (Some(passed), Some(failed)) => {
+                let yes = self.compile_test(process, step, condition, (any, term + 1), passed, unique);
+                let no = self.compile_test(process, step, condition, (any + 1, 0), failed, unique);
+                match constraint.axis() {
+                    Some((var, value, true)) => self.split(var, value as usize, yes, no, unique),
+                    Some((var, value, false)) => self.split(var, value as usize, no, yes, unique),
+                    None => self.test(constraint, yes, no, unique),
+                }
+            }
+            // Region was empty all along
+            (None, None) => REJECT,
+        }
+    }
+
+    // Both sides the same means no split is needed - otherwise share any identical node
+    fn split(&mut self, var: usize, value: usize, below: usize, above: usize, unique: &mut Unique) -> usize {
+        if below == above {
+            return below;
+        }
+        self.node(Node::Split { var, value, below, above }, unique)
+    }
+
+    fn test(&mut self, constraint: &Constraint, yes: usize, no: usize, unique: &mut Unique) -> usize {
+        if yes == no {
+            return yes;
+        }
+        let constraint = *unique.constraints.entry(constraint.clone()).or_insert_with(|| {
+            self.constraints.push(constraint.clone());
+            self.constraints.len() - 1
+        });
+        self.node(Node::Test { constraint, yes, no }, unique)
+    }
+
+    fn node(&mut self, node: Node, unique: &mut Unique) -> usize {
+        *unique.nodes.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        })
+    }
+
+    pub fn nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    // Follows one path from the root - only the splits that matter are on it, so for the puzzle
+    // input that is 16 at most, against 1600 or so steps in the workflows
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut n = self.root;
+        loop {
+            match self.nodes[n] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Split { var, value, below, above } => n = if part.vars[var] < value { below } else { above },
+                Node::Test { constraint, yes, no } => n = if self.constraints[constraint].holds(part) { yes } else { no },
+            }
+        }
+    }
+
+    // Every path to Accept, as disjoint regions of parts
+    pub fn accepted_regions(&self) -> Vec<Region> {
+        let mut regions = Vec::new();
+        let mut pending = vec![(self.root, Region::new(self.full_range.clone()))];
+        while let Some((n, region)) = pending.pop() {
+            match self.nodes[n] {
+                Node::Accept => regions.push(region),
+                Node::Reject => (),
+                Node::Split { var, value, below, above } => {
+                    let mut region_below = region.clone();
+                    let mut region_above = region;
+                    region_below.range.max[var] = value - 1;
+                    region_above.range.min[var] = value;
+                    pending.push((below, region_below));
+                    pending.push((above, region_above));
+                }
+                Node::Test { constraint, yes, no } => {
+                    let constraint = &self.constraints[constraint];
+                    pending.extend(region.with(constraint).map(|r| (yes, r)));
+                    pending.extend(region.with(&constraint.negated()).map(|r| (no, r)));
+                }
+            }
+        }
+        regions
+    }
+
+    // Every path to Accept, as disjoint boxes of parts - regions cut by constraints on more than
+    // one attribute become many boxes
+    pub fn accepted(&self) -> Vec<PartRange> {
+        let mut boxes = Vec::new();
+        for region in self.accepted_regions() {
+            region.for_each_box(&mut |b| boxes.push(b.clone()));
+        }
+        boxes
+    }
+}
+
+#[test]
+fn test_decision_tree() {
+    let (process, parts) = parse(&std::fs::read_to_string("inputs/day19_test").unwrap()).unwrap();
+    let tree = process.compile();
+
+    // Same parts accepted, and same combinations, as interpreting the workflows
+    let accepted = parts.iter().filter(|p| tree.accepts(p)).map(|p| p.sum()).sum::<usize>();
+    assert_eq!(accepted, 19114);
+    let boxes = tree.accepted();
+    assert_eq!(boxes.iter().map(|b| b.combinations().unwrap()).sum::<usize>(), 167409079868000);
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(167409079868000));
+    for (i, a) in boxes.iter().enumerate() {
+        assert!(boxes[i + 1..].iter().all(|b| !a.intersects(b)));
+    }
+    for p in &parts {
+        assert_eq!(tree.accepts(p), boxes.iter().any(|b| b.contains(p)));
+    }
+
+    // Both branches the same, and a rule that can't be reached once x<10 has been taken
+    let xmas = Schema::new(&["x", "m", "a", "s"], PUZZLE_DOMAIN);
+    let tree = Process::assemble(&["in{s>5:lnx,x<10:A,x<5:R,R}", "lnx{m>1548:A,A}"], xmas).unwrap().compile();
+    assert_eq!(tree.nodes(), 4);
+    assert_eq!(tree.accepted(), [
+        PartRange { min: vec![1, 1, 1, 6], max: vec![4000, 4000, 4000, 4000] },
+        PartRange { min: vec![1, 1, 1, 1], max: vec![9, 4000, 4000, 5] },
+    ]);
+}
+
+#[test]
+fn test_schema() {
+    // Attributes from parts, in order, then those only in rules
+    let input = "in{speed<5:A,colour>2:heavy,R}\nheavy{weight>3:R,A}\n\n{weight=4,speed=9,colour=1}\n{speed=3}\n";
+    let (process, parts) = parse_with_domain(input, 0..=9).unwrap();
+    assert_eq!(process.schema(), &Schema::new(&["weight", "speed", "colour"], 0..=9));
+    assert_eq!(parts[0].vars, [4, 9, 1]);
+    assert_eq!(parts[1].vars, [0, 3, 0]);
+
+    // speed<5 accepts 10*5*10, then for speed 5..9 colour 3..9 and weight 0..3 is 4*5*7
+    let tree = process.compile();
+    let boxes = tree.accepted();
+    assert_eq!(boxes.iter().map(|b| b.combinations().unwrap()).sum::<usize>(), 500 + 140);
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(640));
+    assert!(tree.accepts(&parts[1]) && !tree.accepts(&parts[0]));
+
+    assert_eq!(parse("in{x<5:A,R}\n\n{x=1,q=2}\n").unwrap().0.schema().names, ["x", "q"]);
+
+    // Rules without parts are rated on the puzzle's attributes, and any others they use
+    let (process, _) = parse(&std::fs::read_to_string("inputs/day19_test1").unwrap()).unwrap();
+    assert_eq!(process.schema().names, ["x", "m", "a", "s"]);
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(128000000000000));
+    assert_eq!(parse("in{q>5:A,R}").unwrap().0.schema().names, ["x", "m", "a", "s", "q"]);
+
+    // Too many combinations to count
+    let (process, _) = parse("in{x>0:A,R}\n\n{x=1,m=1,a=1,s=1,b=1,c=1,d=1,e=1}\n").unwrap();
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), None);
+    assert_eq!(process.compile().accepted_regions()[0].combinations(), None);
+    let e = parse_with_domain("in{speed<5:A,R}\n\n{speed=1}\n{sp eed=1}\n", 0..=9).unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (4, 2, "Bad var: sp eed"));
+}
+
+#[test]
+fn test_conditions() {
+    // Every part in a small domain, checked one at a time against the workflows
+    let check = |rules: &[&str], domain: RangeInclusive<usize>| {
+        let (process, _) = parse_with_domain(&rules.join("\n"), domain.clone()).unwrap();
+        let tree = process.compile();
+        let vars = process.schema().names.len();
+        let mut part = Part { vars: vec![*domain.start(); vars] };
+        let mut accepted = 0;
+        loop {
+            accepted += process.accepts(&part) as usize;
+            assert_eq!(tree.accepts(&part), process.accepts(&part), "{part:?}");
+            let Some(i) = (0..vars).find(|&i| part.vars[i] < *domain.end()) else { break };
+            part.vars[i] += 1;
+            part.vars[..i].fill(*domain.start());
+        }
+        assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(accepted));
+        assert_eq!(tree.accepted_regions().iter().map(|r| r.combinations().unwrap()).sum::<usize>(), accepted);
+        assert_eq!(tree.accepted().iter().map(|b| b.combinations().unwrap()).sum::<usize>(), accepted);
+        accepted
+    };
+
+    // Rules alone are rated on x, m, a and s - any not used in a rule can take every value
+    assert_eq!(check(&["in{x<=3:A,x>=8:A,R}"], 1..=10), 6 * 1000);
+    assert_eq!(check(&["in{x==3:A,x!=4:R,A}"], 1..=10), 2 * 1000);
+    assert_eq!(check(&["in{x<3&&m>7:A,R}"], 1..=10), 6 * 100);
+    assert_eq!(check(&["in{x<3||m>7:A,R}"], 1..=10), (20 + 30 - 6) * 100);
+    check(&["in{x+m>12:A,x==m||s!=3:ab,2*x-a>=s:R,A}", "ab{x>=m+3&&a<5||s*2==a:A,a==5:A,R}"], 1..=7);
+    check(&["in{x-m<2&&m-x<2&&a+s>x:A,R}"], 0..=8);
+    check(&["in{x+m+a+s>12:A,R}"], 1..=7);
+    check(&["in{2*x-m+3*a-s>4:A,x+m+a<=9&&x+a+s>=6:R,A}"], 1..=7);
+
+    // Constraints that can't be met together
+    assert_eq!(check(&["in{x+m<5:lo,R}", "lo{x+m>6:A,m<=x:R,A}"], 1..=10), 2 * 100);
+    let (process, _) = parse("in{x+m<100:lo,R}\nlo{x+m>200:A,R}").unwrap();
+    assert_eq!(process.compile().nodes(), 2);
+
+    // Sums that would overflow
+    let error = |rule: &str| parse(rule).map(|_| ()).map_err(|e| (e.line, e.column, e.message));
+    let too_large = |column, message: &str| Err((1, column, message.to_string()));
+    assert_eq!(error("in{x*4611686018427387904>5:A,R}"), too_large(4, "Number too large"));
+    assert_eq!(error("in{a+4*4611686018427387904*x>5:A,R}"), too_large(8, "Number too large"));
+    assert_eq!(error("in{5<9223372036854775807-s:A,R}"), too_large(4, "Numbers too large to compare"));
+    assert_eq!(error("in{2000000000000000*x+2000000000000000*m+2000000000000000*a>5:A,R}"), too_large(4, "Numbers too large to compare"));
+    // ... and a value with nothing greater
+    let (process, _) = parse("in{x>18446744073709551615:A,R}").unwrap();
+    assert_eq!(process.compile().nodes(), 2);
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(0));
+
+    // 4000x4000 pairs less those with x+m <= 3000, for each a and s
+    let (process, _) = parse("in{x+m>3000:A,R}").unwrap();
+    assert_eq!(process.compile().accepted_regions()[0].combinations(), Some((16_000_000 - 2999 * 3000 / 2) * 16_000_000));
+    // ... and with three attributes, there are 3000 choose 3 triples with x+m+a <= 3000
+    let (process, _) = parse("in{x+m+a>3000:A,R}").unwrap();
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some((4000usize.pow(3) - 3000 * 2999 * 2998 / 6) * 4000));
+    // ... and four, 3000 choose 4 with x+m+a+s <= 3000
+    let input = "in{x+m+a+s>3000:A,R}";
+    let (process, _) = parse(input).unwrap();
+    let expected = 4000usize.pow(4) - 3000 * 2999 * 2998 * 2997 / 24;
+    assert_eq!(process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0), Some(expected));
+    assert_eq!(process.compile().accepted_regions().iter().map(|r| r.combinations().unwrap()).sum::<usize>(), expected);
+    assert_eq!(validate(input).unwrap(), []);
+
+    let error = |rules| {
+        let e = parse(rules).unwrap_err();
+        (e.column, e.message)
+    };
+    assert_eq!(error("in{x<=:A,R}"), (7, "Expected: number or attribute".to_string()));
+    assert_eq!(error("in{x=5:A,R}"), (5, "Bad op: =".to_string()));
+    assert_eq!(error("in{x+m*a>5:A,R}"), (8, "Only one attribute per term".to_string()));
+    assert_eq!(error("in{x<5&&m:A,R}"), (9, "Bad condition: m".to_string()));
+}
+
+#[test]
+fn test_validate() {
+    let found = |rules: &[&str]| {
+        validate(&rules.join("\n")).unwrap().into_iter().map(|e| (e.line, e.column, e.message)).collect::<Vec<_>>()
+    };
+    let at = |line, column, message: &str| (line, column, message.to_string());
+
+    let test = std::fs::read_to_string("inputs/day19_test").unwrap();
+    assert_eq!(validate(&test).unwrap(), [ParseError::at(10, 1, "Workflow never accepts: gd")]);
+
+    assert_eq!(
+        found(&[
+            "in{x<10:A,x<5:R,m>5||m<=5:ab,R}",
+            "ab{a>10:ab,cd}",
+            "cd{s<1:A,nope}",
+            "old{A}",
+            "ab{R}",
+        ]),
+        [
+            at(1, 11, "Rule never matches - earlier rules take every part it would"),
+            at(1, 30, "Rule never matches - earlier rules take every part it would"),
+            at(2, 1, "Workflow never accepts: ab"),
+            at(2, 9, "Workflow cycle: ab -> ab"),
+            at(3, 1, "Workflow never accepts: cd"),
+            at(3, 4, "Rule never matches"),
+            at(3, 10, "Unknown workflow: nope"),
+            at(4, 1, "Unused workflow: old"),
+            at(5, 1, "Workflow ab already defined on line 2"),
+        ]
+    );
+    assert!(found(&["a{b}", "b{c}", "c{x>5:a,R}", "in{a}"]).contains(&at(3, 7, "Workflow cycle: a -> b -> c -> a")));
+    assert_eq!(found(&["ab{A}"]), [at(0, 0, "No 'in' workflow")]);
+    assert_eq!(validate("in{x<:A,R}").unwrap_err().line, 1);
+
+    // Solving would go round forever
+    let e = parse("in{x>5:a,R}\na{m<10:in,A}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (2, 8, "Workflow cycle: in -> a -> in"));
+    let e = parse("in{x+m>10:a,R}\na{x>m:in,A}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (2, 7, "Workflow cycle: in -> a -> in"));
+    // ... but loops that 'in' never leads to, or that no part could go round, are left to validate
+    for rules in [["in{A}", "b{c}", "c{b}"].as_slice(), &["in{x>5:a,R}", "a{x<3:in,A}"], &["in{x+m>10:a,R}", "a{x+m<5:in,A}"]] {
+        assert!(parse(&rules.join("\n")).is_ok(), "{rules:?}");
+        assert!(found(rules).iter().any(|(_, _, message)| message.starts_with("Workflow cycle")), "{rules:?}");
+    }
+    // ... and would take the second definition, which loops
+    let e = parse("in{a}\na{A}\na{in}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (3, 1, "Workflow a already defined on line 2"));
+    let e = parse("in{A}\nA{R}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (2, 1, "Reserved workflow name: A"));
+    assert_eq!(found(&["in{A}", "A{R}"]), [at(2, 1, "Reserved workflow name: A")]);
+
+    // Parts would run on into the next workflow
+    let e = parse("in{x>5:e,R}\ne{}\nf{A}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (2, 1, "Workflow has no rules: e"));
+    let e = parse("in{x>5:e,R}\ne{m<10:A}\nf{A}").unwrap_err();
+    assert_eq!((e.line, e.column, e.message.as_str()), (2, 3, "Last rule must always apply"));
+    assert_eq!(
+        found(&["in{x>5:e,f}", "e{}", "f{m<10:A}"]),
+        [at(2, 1, "Workflow has no rules: e"), at(2, 1, "Workflow never accepts: e"), at(3, 3, "Last rule must always apply")]
+    );
+}
+
+#[test]
+fn test_explain() {
+    let (process, parts) = parse(&std::fs::read_to_string("inputs/day19_test").unwrap()).unwrap();
+    let explain = |part| process.explain(part).to_string();
+
+    // Paths from the puzzle
+    assert_eq!(explain(&parts[0]), "in[1] -> qqz[0] -> qs[1] -> lnx[0] -> A");
+    assert_eq!(explain(&parts[1]), "in[0] -> px[2] -> rfg[0] -> gd[1] -> R");
+    assert_eq!(explain(&parts[2]), "in[1] -> qqz[1] -> hdj[1] -> pv[1] -> A");
+    assert_eq!(explain(&parts[3]), "in[0] -> px[0] -> qkq[1] -> crn[1] -> R");
+    assert_eq!(explain(&parts[4]), "in[0] -> px[2] -> rfg[2] -> A");
+
+    let e = process.explain(&Part::from_string("{x=1,m=2091,a=2006,s=1}", process.schema()).unwrap());
+    assert_eq!(e, Explanation { path: vec![("in".to_string(), 0), ("px".to_string(), 1)], accepted: true });
+}
@@ -0,0 +1,13 @@
+use crate::{bench, Answer, ParseError};
+use super::day19;
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let (process, parts) = day19::parse(input)?;
+    bench::parsed();
+
+    let tree = process.compile();
+    let sum = parts.iter().filter(|p| tree.accepts(p)).map(|p| p.sum()).sum::<usize>();
+
+    Ok(sum.into())
+    // 287054
+}
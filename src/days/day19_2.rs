@@ -0,0 +1,22 @@
+use crate::{bench, Answer, ParseError};
+use super::day19;
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let (process, _) = day19::parse(input)?;
+    bench::parsed();
+
+    let tree = process.compile();
+    let combinations = tree.accepted_regions().iter().try_fold(0usize, |c, r| c.checked_add(r.combinations()?));
+
+    // Unsolved if there are too many to count
+    Ok(combinations.and_then(|c| i64::try_from(c).ok()).into())
+    // 131619440296497
+}
+
+#[test]
+fn test_too_many() {
+    assert_eq!(solve(&std::fs::read_to_string("inputs/day19_test1").unwrap()), Ok(Answer::Number(128000000000000)));
+    // 4000^5 fits, 4000^6 doesn't
+    assert_eq!(solve("in{x>0:A,R}\n\n{x=1,m=1,a=1,s=1,b=1}\n"), Ok(Answer::Number(4000i64.pow(5))));
+    assert_eq!(solve("in{x>0:A,R}\n\n{x=1,m=1,a=1,s=1,b=1,c=1}\n"), Ok(Answer::Unsolved));
+}
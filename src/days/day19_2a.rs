@@ -0,0 +1,14 @@
+use crate::{bench, Answer, ParseError};
+use super::day19::{self, PartRange};
+
+// Interpret the workflows directly, splitting ranges at each rule
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let (process, _) = day19::parse(input)?;
+    bench::parsed();
+
+    let combinations = process.add_combinations(process.entry(), PartRange::full_range(process.schema()), 0);
+
+    // Unsolved if there are too many to count
+    Ok(combinations.and_then(|c| i64::try_from(c).ok()).into())
+    // 131619440296497
+}
@@ -0,0 +1,19 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_lines;
+
+// First and last digit on the line, as a two digit number
+fn calibration(l: &str) -> Result<u32, ParseError> {
+    let digits = l.chars().filter_map(|c| c.to_digit(10)).collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (digits.first(), digits.last()) else {
+        return Err(ParseError::new("No digits"));
+    };
+    Ok(first * 10 + last)
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let calibrations = parse_lines(input, calibration)?;
+    bench::parsed();
+
+    let total = calibrations.iter().sum::<u32>();
+    Ok(total.into())
+}
@@ -1,6 +1,8 @@
-use std::fs;
 use regex::Regex;
 
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_lines;
+
 fn digit_from_string(s :&str) -> u32 {
     match s {
         "0" | "zero" | "orez" => 0,
@@ -17,18 +19,21 @@ fn digit_from_string(s :&str) -> u32 {
     }
 }
 
-fn main() {
-    println!("Day 1_2");
-    let mut total  = 0;
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
     let re_f = Regex::new("[0-9]|zero|one|two|three|four|five|six|seven|eight|nine").unwrap();
     let re_b = Regex::new("[0-9]|orez|eno|owt|eerht|ruof|evif|xis|neves|thgie|enin").unwrap();
 
-    for l in fs::read_to_string("inputs/day1").unwrap().lines() {
-        let digits_f = re_f.find_iter(l).map(|m| digit_from_string(m.as_str())).collect::<Vec<_>>();
-        let digits_b = re_b.find_iter(&l.chars().rev().collect::<String>()).map(|m| digit_from_string(m.as_str())).collect::<Vec<_>>();
+    let calibrations = parse_lines(input, |l| {
+        let digit_f = re_f.find(l).map(|m| digit_from_string(m.as_str()));
+        let digit_b = re_b.find(&l.chars().rev().collect::<String>()).map(|m| digit_from_string(m.as_str()));
 
-        let n = digits_f.first().unwrap() * 10 + digits_b.first().unwrap();
-        total += n;
-    }
-    println!("Total {}", total);
+        match (digit_f, digit_b) {
+            (Some(f), Some(b)) => Ok(f * 10 + b),
+            _ => Err(ParseError::new("No digits")),
+        }
+    })?;
+    bench::parsed();
+
+    let total = calibrations.iter().sum::<u32>();
+    Ok(total.into())
 }
@@ -0,0 +1,16 @@
+use crate::{bench, Answer, ParseError};
+use crate::circuit::{Circuit, Simulator};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let circuit = Circuit::from_text(input)?;
+    bench::parsed();
+
+    let mut sim = Simulator::new(&circuit);
+    for _i in 0..1000 {
+        sim.press_button();
+    }
+
+    let (low_pulses, high_pulses) = sim.pulse_counts();
+    Ok((low_pulses * high_pulses).into())
+    // 681194780
+}
@@ -0,0 +1,116 @@
+use std::cell::RefCell;
+
+use crate::{bench, Answer, ParseError};
+use crate::circuit::{Circuit, Level, ModuleType, Simulator};
+use super::day8_2::lcm;
+
+// Give up on counters that haven't fired twice by now
+const MAX_PRESSES: usize = 1 << 20;
+
+// rx gets a low pulse when the conjunction feeding it has every input high at once. Each of
+// those inputs comes from a separate counter that sends a high pulse every so many presses.
+//
+// Returns the number of presses between each counter's pulses, or None if the circuit isn't
+// built like that
+fn counter_periods(circuit: &Circuit) -> Option<Vec<usize>> {
+    let rx = circuit.find("rx")?;
+    let &[last] = circuit.module(rx).inputs.as_slice() else {
+        return None;
+    };
+    if circuit.module(last).typ != ModuleType::Conjunction {
+        return None;
+    }
+    let inputs = &circuit.module(last).inputs;
+
+    // Counters can only be run together if they share nothing but the broadcaster
+    let broadcaster = circuit.broadcaster()?;
+    let mut counter = vec![None; circuit.modules().len()];
+    for (i, &input) in inputs.iter().enumerate() {
+        for n in circuit.feeding(input) {
+            if n == last || counter[n].is_some_and(|c| c != i) {
+                return None;
+            }
+            if n != broadcaster {
+                counter[n] = Some(i);
+            }
+        }
+    }
+
+    // Presses on which each input sends a high pulse
+    let highs = RefCell::new(vec![Vec::new(); inputs.len()]);
+    let mut sim = Simulator::new(circuit);
+    sim.subscribe(|press, pulse| {
+        if pulse.dest == last && pulse.level == Level::High {
+            let presses = &mut highs.borrow_mut()[pulse.input];
+            if presses.last() != Some(&press) {
+                presses.push(press);
+            }
+        }
+    });
+    while highs.borrow().iter().any(|h| h.len() < 2) {
+        if sim.presses() >= MAX_PRESSES {
+            return None;
+        }
+        sim.press_button();
+    }
+    drop(sim);
+
+    // Each counter has to start again from where it was before the first press, so that it
+    // keeps firing at multiples of its period
+    highs.into_inner().into_iter().map(|h| (h[1] == 2 * h[0]).then_some(h[0])).collect()
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let circuit = Circuit::from_text(input)?;
+    bench::parsed();
+
+    // All counters fire together after the lowest common multiple of their periods
+    let presses = counter_periods(&circuit).and_then(|p| lcm(&p));
+
+    Ok(presses.into())
+    // 238593356738827
+}
+
+#[test]
+fn test_counters() {
+    // Counters of 6 and 4 - each conjunction fires when the bits set in its count are all on, then
+    // adds enough to the count to overflow it back to 0
+    let input = [
+        "broadcaster -> a0, b0",
+        "%a0 -> a1",
+        "%a1 -> a2, ca",
+        "%a2 -> ca",
+        "&ca -> a0, a0, ia",
+        "&ia -> last",
+        "%b0 -> b1",
+        "%b1 -> b2",
+        "%b2 -> cb",
+        "&cb -> b0, b1, b0, ib",
+        "&ib -> last",
+        "&last -> rx",
+    ]
+    .join("\n");
+    let circuit = Circuit::from_text(&input).unwrap();
+    assert_eq!(counter_periods(&circuit), Some(vec![6, 4]));
+    assert_eq!(solve(&input), Ok(Answer::Number(12)));
+
+    // Same as pressing until rx gets a low pulse
+    let rx = circuit.find("rx").unwrap();
+    let first = std::cell::Cell::new(None);
+    let mut sim = Simulator::new(&circuit);
+    sim.subscribe(|press, pulse| {
+        if pulse.dest == rx && pulse.level == Level::Low && first.get().is_none() {
+            first.set(Some(press));
+        }
+    });
+    while first.get().is_none() {
+        sim.press_button();
+    }
+    drop(sim);
+    assert_eq!(first.get(), Some(12));
+
+    // Counters that share a flip-flop can't be run separately
+    let shared = input.replace("%b2 -> cb", "%b2 -> cb, a2");
+    assert_eq!(counter_periods(&Circuit::from_text(&shared).unwrap()), None);
+    assert_eq!(solve("broadcaster -> a\n%a -> b"), Ok(Answer::Unsolved));
+}
@@ -0,0 +1,74 @@
+use crate::{bench, Answer, ParseError};
+use crate::grid::Grid;
+
+#[derive(Debug)]
+struct Map {
+	rocks: Grid<bool>,
+	start: (i32, i32)
+}
+
+impl Map {
+	fn from_string(str: &str) -> Result<Self, ParseError> {
+		let rocks = Grid::from_text(str)?;
+		let start = str.lines().enumerate()
+			.find_map(|(y,l)| l.find('S').map(|x| (x as i32, y as i32)))
+			.ok_or(ParseError::new("No start position"))?;
+
+		Ok(Map { rocks, start})
+	}
+
+	fn start_marks(&self) -> Grid<bool> {
+		let mut m = Grid::new(self.rocks.width, self.rocks.height, false);
+		m.set(self.start.0, self.start.1, true);
+		m
+	}
+
+	fn grow_marks(&self, prev: &Grid<bool>) -> Grid<bool> {
+		let mut next = Grid::new(self.rocks.width, self.rocks.height, false);
+		for y in 0 .. self.rocks.height as i32 {
+			for x in 0 .. self.rocks.width as i32 {
+				// If a clear cell, and any of neighbours are live in previous step, this cell is marked
+				if !self.rocks.get(x, y).unwrap() {
+					let c = self.rocks.neighbours4(x, y).any(|(nx, ny)| prev.get(nx, ny).unwrap());
+					next.set(x, y, c);
+				}
+			}
+		}
+		next
+	}
+
+
+	fn dump_marks(&self, marks:&Grid<bool>) {
+		println!("-- {} x {}", self.rocks.width, self.rocks.height);
+		for y in 0 .. self.rocks.height as i32 {
+			for x in 0 .. self.rocks.width as i32 {
+				let c = match (marks.get(x, y).unwrap(), self.rocks.get(x, y).unwrap()) {
+					(false, false) => '.',
+					(true, false) => 'O',
+					(false, true) => '#',
+					(true, true) => '?',
+				};
+				print!("{}", c);
+			}
+			println!("");
+		}
+	}
+
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let map = Map::from_string(input)?;
+	bench::parsed();
+
+	let mut marks = map.start_marks();
+
+	for _step in 1..=64 {
+//		println!("Step: {_step}");
+		marks = map.grow_marks(&marks);
+	}
+//	map.dump_marks(&marks);
+
+	let reachable_count = marks.cells.iter().filter(|&&c| c).count();
+	Ok(reachable_count.into())
+	// 3574
+}
\ No newline at end of file
@@ -0,0 +1,122 @@
+//
+use crate::{bench, Answer, ParseError};
+use crate::grid::Grid;
+
+#[derive(Debug)]
+struct Map {
+	rocks: Grid<bool>,
+	start: (i32, i32)
+}
+
+impl Map {
+	fn parse_tile(str: &str) -> Result<Grid<bool>, ParseError> {
+		let tile = Grid::from_text(str)?;
+		// Tile counts below are for the 131x131 puzzle input, with start in the middle
+		if tile.width != 131 || tile.height != 131 {
+			return Err(ParseError::new(format!("Map must be 131x131, not {}x{}", tile.width, tile.height)));
+		}
+		Ok(tile)
+	}
+
+	fn from_tile(tile: &Grid<bool>, repeat: i32) -> Self {
+		let rocks = tile.tiled(repeat as usize, repeat as usize);
+		let start = (repeat /2 * 131 + 65, repeat /2 * 131 + 65);
+
+		Map { rocks, start}
+	}
+
+	fn start_marks(&self) -> Grid<bool> {
+		let mut m = Grid::new(self.rocks.width, self.rocks.height, false);
+		m.set(self.start.0, self.start.1, true);
+		m
+	}
+
+	fn grow_marks(&self, prev: &Grid<bool>) -> Grid<bool> {
+		let mut next = Grid::new(self.rocks.width, self.rocks.height, false);
+		for y in 0 .. self.rocks.height as i32 {
+			for x in 0 .. self.rocks.width as i32 {
+				// If a clear cell, and any of neighbours are live in previous step, this cell is marked
+				if !self.rocks.get(x, y).unwrap() {
+					let c = self.rocks.neighbours4(x, y).any(|(nx, ny)| prev.get(nx, ny).unwrap());
+					next.set(x, y, c);
+				}
+			}
+		}
+		next
+	}
+
+
+	fn dump_marks(&self, marks:&Grid<bool>) {
+		println!("-- {} x {}. start:{:?}", self.rocks.width, self.rocks.height, self.start);
+		for y in 0 .. self.rocks.height as i32 {
+			for x in 0 .. self.rocks.width as i32 {
+				let c = match (marks.get(x, y).unwrap(), self.rocks.get(x, y).unwrap()) {
+					(false, false) => '.',
+					(true, false) => 'O',
+					(false, true) => '#',
+					(true, true) => '?',
+				};
+				print!("{}", c);
+			}
+			println!("");
+		}
+	}
+
+	fn reachable_in_tile(&self, marks:&Grid<bool>, tx:i32, ty:i32, size:i32) -> usize {
+		let mut count = 0;
+		for y in ty * size .. (ty+1) * size {
+			for x in tx * size .. (tx+1) * size {
+				if marks.get(x,y).unwrap() {
+					count +=1;
+				}
+			}
+		}
+		count
+	}
+}
+
+fn caclulate_reachable(n: usize) -> usize {
+	(5539 + 5526 + 5516 + 5529) + 			// Corners
+	n * (944 + 929 + 937 + 943) + 			// Small diagonal edge pieces
+	(n-1) * (6442 + 6433 + 6429 + 6423) + 	// Large diagonal edge pieces
+	n * n * (7327) + 						// First full inside tile
+	(n-1) * (n-1) * (7336) 					// Second full inside tile
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+	let tile = Map::parse_tile(input)?;
+	bench::parsed();
+
+	for n in 1 .. 7 {
+		let map = Map::from_tile(&tile, 1 + 2*n);
+
+		let mut marks = map.start_marks();
+		let steps = 65 + 131 * n;
+		for _step in 1..=steps {
+			marks = map.grow_marks(&marks);
+		}
+		let reachable_count = marks.cells.iter().filter(|&&c| c).count();
+//		println!("Iteration:{n} steps:{steps} reachable:{reachable_count}");
+
+		// Check tile based calculation against simulation
+		let calcualted_reachable = caclulate_reachable(n as usize);
+		assert_eq!(reachable_count, calcualted_reachable);
+
+		// for ty in 0 .. (1+2*n) {
+		// 	for tx in 0 .. (1+2*n) {
+		// 		let c = map.reachable_in_tile(&marks, tx, ty, 131);
+		// 		if c > 0 {
+		// 			print!("{c:5}");
+		// 		} else {
+		// 			print!("     ");
+		// 		}
+		// 	}
+		// 	println!("");
+		// }
+	}
+
+	// Calcualte final answer
+	let iterations = 202300;
+	Ok(caclulate_reachable(iterations).into())
+	// 600090522932119
+}
\ No newline at end of file
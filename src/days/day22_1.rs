@@ -1,4 +1,7 @@
-use std::{fs, collections::BinaryHeap};
+use std::collections::BinaryHeap;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
 
 // 128 mask bits - can fit 10x10 xy dims
 const MASK_WIDTH:i32 = 10;
@@ -19,21 +22,20 @@ impl Point {
         Point { x, y, z }
     }
 
-    fn from_string(s: &str) -> Option<Point> {
-        let r = s
+    fn from_string(s: &str) -> Result<Point, ParseError> {
+        let v = s
             .split_terminator(',')
-            .map(str::trim)
-            .map(|s| s.parse::<i32>())
-            .collect::<Result<Vec<_>, _>>();
-
-        match r {
-            Ok(v) if v.len() == 3 => Some(Point {
-                x: v[0],
-                y: v[1],
-                z: v[2],
-            }),
-            _ => None
+            .map(|c| parse_value::<i32>(c).map_err(|e| e.within(s, c)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if v.len() != 3 {
+            return Err(ParseError::new(format!("Expected x,y,z: {s}")));
         }
+        Ok(Point {
+            x: v[0],
+            y: v[1],
+            z: v[2],
+        })
     }
 }
 
@@ -49,18 +51,26 @@ fn brick_label(idx: usize) -> char {
 }
 
 impl Brick {
-    fn from_string(s: &str) -> Option<Brick> {
-
-        let mut pts = s.split('~');
-        let mut start = Point::from_string(pts.next()?)?;
-        let mut end = Point::from_string(pts.next()?)?;
+    // eg. "1,0,1~1,2,1"
+    fn from_string(s: &str) -> Result<Brick, ParseError> {
+
+        let (start_text, end_text) = s.split_once('~').ok_or(ParseError::new("Expected: <start>~<end>"))?;
+        let mut start = Point::from_string(start_text).map_err(|e| e.within(s, start_text))?;
+        let mut end = Point::from_string(end_text).map_err(|e| e.within(s, end_text))?;
+
+        // Must fit in xy mask, and be above ground
+        for p in [start, end] {
+            if p.x < 0 || p.y < 0 || p.x >= MASK_WIDTH || p.y >= MASK_HEIGHT || p.z < 1 {
+                return Err(ParseError::new(format!("Brick out of range: {s}")));
+            }
+        }
 
         // Make sure start z <= end z
         if start.z > end.z {
             (start, end) = (end, start)
         }
 
-        Some(Brick { start, end, mask: Brick::make_mask(start, end) })
+        Ok(Brick { start, end, mask: Brick::make_mask(start, end) })
     }
 
     // Make a bitmask of brick's proejction onto xy plane
@@ -84,24 +94,23 @@ impl Brick {
     }
 }
 
-fn main() {
-    println!("Hello Day 22 1!");
-
-    let input = fs::read_to_string("inputs/day22").unwrap();
-
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
     let mut bricks = Vec::new();
     let mut height = 0;
 	// Heap of brick indices sorted by -ve height
     let mut bricks_heap = BinaryHeap::new();
 
-    for l in input.lines().filter(|s| !s.is_empty()) {
-    	if let Some(b) = Brick::from_string(l) {
-    		bricks_heap.push((-b.lowest(), bricks.len()));
-    		height = i32::max(b.lowest(), height);
+    for (i, l) in input.lines().enumerate().filter(|(_, s)| !s.is_empty()) {
+    	let b = Brick::from_string(l).map_err(|e| e.with_line(i + 1))?;
+		bricks_heap.push((-b.lowest(), bricks.len()));
+		height = i32::max(b.highest(), height);
 
-    		bricks.push(b);
-    	}
+		bricks.push(b);
     }
+    bench::parsed();
+
+    // Room for the ground, the highest brick and an empty layer above it
+    let height = height + 2;
 
     // Bitmask of each settled level of pile - level 0 is solid
     let mut pile_masks = vec![0; height as usize];
@@ -151,5 +160,5 @@ fn main() {
     	count += 1;
     }
 
-    println!("Removable: {count}");
+    Ok(count.into())
 }
@@ -0,0 +1,273 @@
+// Hiking map shared by both parts of day 23
+use crate::ParseError;
+use crate::grid::{Cell, Grid};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Direction {
+    E,
+    N,
+    W,
+    S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Element {
+    Slope(Direction),
+    Path,
+    Forest,
+}
+
+impl Cell for Element {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '>' => Some(Element::Slope(Direction::E)),
+            '^' => Some(Element::Slope(Direction::N)),
+            '<' => Some(Element::Slope(Direction::W)),
+            'v' => Some(Element::Slope(Direction::S)),
+            '.' => Some(Element::Path),
+            '#' => Some(Element::Forest),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Element::Slope(Direction::E) => '>',
+            Element::Slope(Direction::N) => '^',
+            Element::Slope(Direction::W) => '<',
+            Element::Slope(Direction::S) => 'v',
+            Element::Path => '.',
+            Element::Forest => '#',
+        }
+    }
+}
+
+// Whether slopes can only be walked down (part 1), or are just path (part 2)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlopeMode {
+    Directed,
+    Undirected,
+}
+
+#[derive(Debug)]
+pub struct Map {
+    elements: Grid<Element>,
+    start: (i32, i32),
+    end: (i32, i32),
+}
+
+impl Map {
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let elements = Grid::from_text(input)?;
+
+        // Start and end are the path cells in the top and bottom rows
+        let last = elements.height.checked_sub(1).ok_or(ParseError::new("Empty map"))?;
+        let start = elements.row(0).iter().position(|&e| e == Element::Path)
+            .ok_or(ParseError::at(1, 0, "No start path"))?;
+        let end = elements.row(last).iter().position(|&e| e == Element::Path)
+            .ok_or(ParseError::at(last + 1, 0, "No end path"))?;
+
+        Ok(Map {
+            elements,
+            start: (start as i32, 0),
+            end: (end as i32, last as i32),
+        })
+    }
+
+    fn at(&self, x: i32, y: i32) -> Element {
+        self.elements.get_or(x, y, Element::Forest)
+    }
+
+    // Cells that can be walked to from x,y
+    fn exits(&self, x: i32, y: i32, mode: SlopeMode) -> Vec<(i32, i32)> {
+        let e = self.at(x, y);
+        [
+            (Direction::E, 1, 0),
+            (Direction::N, 0, -1),
+            (Direction::W, -1, 0),
+            (Direction::S, 0, 1),
+        ]
+        .into_iter()
+        .filter(|&(d, _, _)| mode == SlopeMode::Undirected || e == Element::Path || e == Element::Slope(d))
+        .map(|(_, dx, dy)| (x + dx, y + dy))
+        .filter(|&(nx, ny)| self.at(nx, ny) != Element::Forest)
+        .collect()
+    }
+
+    // Longest hike from start to end that doesn't visit anywhere twice
+    pub fn longest_hike(&self, mode: SlopeMode) -> Option<usize> {
+        Graph::create(self, mode).longest_path()
+    }
+}
+
+// Junction in map - start, end, or a path cell with more than two ways out
+#[derive(Debug)]
+struct Node {
+    position: (i32, i32),
+    edges:[u32;4]
+}
+
+// Path from one junction to another
+#[derive(Debug)]
+struct Edge {
+    cost: u32,
+    nodes: (u32, u32)
+}
+
+// Map contracted to junctions, and the paths between them
+#[derive(Debug)]
+struct Graph {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+    start: u32,
+    end: u32,
+    // Edges leading into end
+    end_edges: usize,
+}
+
+const NO_EDGE: u32 = u32::MAX;
+
+impl Graph {
+    fn create(map: &Map, mode: SlopeMode) -> Graph {
+        // Find junctions - slopes don't matter here, just whether there is a way through
+        let mut nodes = Vec::new();
+        let mut node_at = Grid::new(map.elements.width, map.elements.height, NO_EDGE);
+        for y in 0..map.elements.height as i32 {
+            for x in 0..map.elements.width as i32 {
+                if map.at(x, y) != Element::Forest
+                    && (map.exits(x, y, SlopeMode::Undirected).len() > 2 || (x, y) == map.start || (x, y) == map.end)
+                {
+                    node_at.set(x, y, nodes.len() as u32);
+                    nodes.push(Node { position: (x, y), edges:[NO_EDGE; 4] });
+                }
+            }
+        }
+
+        // Follow each way out of each junction to the next junction
+        let mut edges = Vec::new();
+        for (n, node) in nodes.iter_mut().enumerate() {
+            let (nx, ny) = node.position;
+            let mut slot = 0;
+            for (mut x, mut y) in map.exits(nx, ny, mode) {
+                let (mut px, mut py) = (nx, ny);
+                let mut cost = 1;
+                while node_at.get_or(x, y, NO_EDGE) == NO_EDGE {
+                    let Some(&next) = map.exits(x, y, mode).iter().find(|&&e| e != (px, py)) else {
+                        break;
+                    };
+                    (px, py) = (x, y);
+                    (x, y) = next;
+                    cost += 1;
+                }
+
+                // Dead ends, and paths blocked by slopes, are no use
+                let other = node_at.get_or(x, y, NO_EDGE);
+                if other == NO_EDGE {
+                    continue;
+                }
+                node.edges[slot] = edges.len() as u32;
+                edges.push(Edge { cost, nodes: (n as u32, other) });
+                slot += 1;
+            }
+        }
+
+        let start = node_at.get_or(map.start.0, map.start.1, NO_EDGE);
+        let end = node_at.get_or(map.end.0, map.end.1, NO_EDGE);
+        let end_edges = edges.iter().filter(|e| e.nodes.1 == end).count();
+        Graph { nodes, edges, start, end, end_edges }
+    }
+
+    fn node_edges(&self, n: u32) -> impl Iterator<Item = &Edge> + '_ {
+        self.nodes[n as usize].edges.iter().take_while(|&&e| e != NO_EDGE).map(|&e| &self.edges[e as usize])
+    }
+
+    // Length of longest path from start to end that does not visit any junction twice
+    fn longest_path(&self) -> Option<usize> {
+        // Most that each junction could add to a path - the longest path into it
+        let mut best_in = vec![0; self.nodes.len()];
+        for e in &self.edges {
+            best_in[e.nodes.1 as usize] = usize::max(best_in[e.nodes.1 as usize], e.cost as usize);
+        }
+        let remaining = best_in.iter().sum::<usize>() - best_in[self.start as usize];
+
+        let mut visited = vec![false; self.nodes.len()];
+        visited[self.start as usize] = true;
+
+        let mut longest = None;
+        self.search(self.start, &mut visited, 0, remaining, &best_in, &mut longest);
+        longest
+    }
+
+    // Depth first search of paths onwards from 'node', giving up on any that cannot beat 'longest'
+    fn search(&self, node: u32, visited: &mut [bool], length: usize, remaining: usize, best_in: &[usize], longest: &mut Option<usize>) {
+        if node == self.end {
+            *longest = Some(longest.map_or(length, |l| l.max(length)));
+            return;
+        }
+        if longest.is_some_and(|l| length + remaining <= l) {
+            return;
+        }
+
+        let edges = self.node_edges(node).collect::<Vec<_>>();
+
+        // If this junction has the only way into the end, going anywhere else would leave no way back to it
+        if self.end_edges == 1 {
+            if let Some(e) = edges.iter().find(|e| e.nodes.1 == self.end) {
+                self.search(self.end, visited, length + e.cost as usize, 0, best_in, longest);
+                return;
+            }
+        }
+
+        for e in edges {
+            let next = e.nodes.1;
+            if !visited[next as usize] {
+                let r = remaining - best_in[next as usize];
+                visited[next as usize] = true;
+                self.search(next, visited, length + e.cost as usize, r, best_in, longest);
+                visited[next as usize] = false;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_modes() {
+    let map = Map::from_string(&std::fs::read_to_string("inputs/day23_test").unwrap()).unwrap();
+    assert_eq!(map.longest_hike(SlopeMode::Directed), Some(94));
+    assert_eq!(map.longest_hike(SlopeMode::Undirected), Some(154));
+
+    // Junctions are the same either way, but slopes cut the paths between them
+    let directed = Graph::create(&map, SlopeMode::Directed);
+    let undirected = Graph::create(&map, SlopeMode::Undirected);
+    assert_eq!(directed.nodes.len(), 9);
+    assert_eq!(undirected.nodes.len(), 9);
+    assert_eq!(undirected.edges.len(), 24);
+    assert_eq!(directed.edges.len(), 12);
+
+    // A slope pointing back at the start blocks the only way out
+    let blocked = Map::from_string("#.#\n#^#\n#.#").unwrap();
+    assert_eq!(blocked.longest_hike(SlopeMode::Directed), None);
+    assert_eq!(blocked.longest_hike(SlopeMode::Undirected), Some(2));
+
+    // Two ways into the end - the loop south is longer than going straight there
+    let rows = ["#####.###", "#.....###", "#.###.###", "#.###...#", "#.#####.#", "#.###...#", "#.....###"];
+    let map = Map::from_string(&rows.join("\n")).unwrap();
+    assert_eq!(map.longest_hike(SlopeMode::Directed), Some(14));
+    assert_eq!(map.longest_hike(SlopeMode::Undirected), Some(14));
+}
+
+#[test]
+fn test_large() {
+    // Corridor wider than 255, with a dead end spur off every other cell - more than 64 junctions
+    let width = 301;
+    let rows = [
+        format!("#.{}", "#".repeat(width - 2)),
+        format!("#{}#", ".".repeat(width - 2)),
+        (0..width).map(|x| if (x >= 2 && x < width - 3 && x % 2 == 0) || x == width - 2 { '.' } else { '#' }).collect(),
+        format!("{}.#", "#".repeat(width - 2)),
+    ];
+    let map = Map::from_string(&rows.join("\n")).unwrap();
+    assert!(Graph::create(&map, SlopeMode::Directed).nodes.len() > 64);
+    assert_eq!(map.longest_hike(SlopeMode::Directed), Some(width));
+    assert_eq!(map.longest_hike(SlopeMode::Undirected), Some(width));
+}
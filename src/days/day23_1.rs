@@ -0,0 +1,12 @@
+use crate::{bench, Answer, ParseError};
+use super::day23::{Map, SlopeMode};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let map = Map::from_string(input)?;
+    bench::parsed();
+
+    let longest = map.longest_hike(SlopeMode::Directed);
+
+    Ok(longest.into())
+    // 2034
+}
@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, Clone)]
 struct Particle {
@@ -36,25 +37,33 @@ impl Particle {
 	}
 }
 
-fn main() {
-	println!("Hello Day 24 1!");
+// eg. "19, 13, 30"
+fn parse_triple(l: &str, s: &str) -> Result<[i64; 3], ParseError> {
+	let v = s.split_terminator(',').map(|c| parse_value::<i64>(c).map_err(|e| e.within(l, c))).collect::<Result<Vec<_>, _>>()?;
+	<[i64; 3]>::try_from(v).map_err(|_| ParseError::new("Expected: x, y, z").within(l, s))
+}
+
+// eg. "19, 13, 30 @ -2,  1, -2" - position and velocity
+fn parse_particle(l: &str) -> Result<([i64; 3], [i64; 3]), ParseError> {
+	let Some((ps, vs)) = l.split_once('@') else {
+		return Err(ParseError::new("Expected: <position> @ <velocity>"));
+	};
+	Ok((parse_triple(l, ps)?, parse_triple(l, vs)?))
+}
 
-	// let input = fs::read_to_string("inputs/day24_test").unwrap();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
 	// let area_low = 7;
 	// let area_high = 27;
 
-	let input = fs::read_to_string("inputs/day24").unwrap();
 	let area_low = 200000000000000;
 	let area_high = 400000000000000;
 
 	let mut particles = Vec::new();
 
-	for l in input.lines() {
-		let (ps,vs) = l.split_once('@').unwrap();
-		let p = ps.split_terminator(',').map(|s| s.trim().parse::<i64>()).collect::<Result<Vec<_>,_>>().unwrap();
-		let v = vs.split_terminator(',').map(|s| s.trim().parse::<i64>()).collect::<Result<Vec<_>,_>>().unwrap();
+	for (p, v) in parse_lines(input, parse_particle)? {
 		particles.push(Particle { position: (p[0], p[1], p[2]), velocity: (v[0], v[1], v[2])});
 	}
+	bench::parsed();
 
 	let mut count = 0;
 	for i in 0 .. particles.len() {
@@ -65,6 +74,6 @@ fn main() {
 		}
 	}
 
-	println!("Collisions: {count}");
+	Ok(count.into())
 	// 28174
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, Clone)]
 struct Particle {
@@ -65,6 +66,11 @@ fn spiral(n: i64) -> (i64, i64) {
 // Search for a velocity that makes all particles intersect at same point when projected onto 2d plane
 //
 fn find_velocity_2d(particles: &[Particle], axes: (usize, usize)) -> Option<((i64,i64),i64)> {
+	// Need at least two particles to intersect
+	if particles.len() < 2 {
+		return None;
+	}
+
 	// Spiral outwards for x,y velocities from 0,0
 	'outer: for n in  0..1000000 {
 		let vxy = spiral(n);
@@ -96,24 +102,33 @@ fn find_velocity_2d(particles: &[Particle], axes: (usize, usize)) -> Option<((i6
 				continue 'outer;
 			}
 		}
-		println!("Found point at {vxy:?}: {point:?}");
+//		println!("Found point at {vxy:?}: {point:?}");
 		return Some(((vxy), t));
 	}
 	None
 }
 
-fn main() {
-	println!("Hello Day 24 2!");
+// eg. "19, 13, 30"
+fn parse_triple(l: &str, s: &str) -> Result<[i64; 3], ParseError> {
+	let v = s.split_terminator(',').map(|c| parse_value::<i64>(c).map_err(|e| e.within(l, c))).collect::<Result<Vec<_>, _>>()?;
+	<[i64; 3]>::try_from(v).map_err(|_| ParseError::new("Expected: x, y, z").within(l, s))
+}
 
-	let input = fs::read_to_string("inputs/day24").unwrap();
+// eg. "19, 13, 30 @ -2,  1, -2" - position and velocity
+fn parse_particle(l: &str) -> Result<([i64; 3], [i64; 3]), ParseError> {
+	let Some((ps, vs)) = l.split_once('@') else {
+		return Err(ParseError::new("Expected: <position> @ <velocity>"));
+	};
+	Ok((parse_triple(l, ps)?, parse_triple(l, vs)?))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
 	let mut particles = Vec::new();
 
-	for l in input.lines() {
-		let (ps,vs) = l.split_once('@').unwrap();
-		let p = ps.split_terminator(',').map(|s| s.trim().parse::<i64>()).collect::<Result<Vec<_>,_>>().unwrap();
-		let v = vs.split_terminator(',').map(|s| s.trim().parse::<i64>()).collect::<Result<Vec<_>,_>>().unwrap();
-		particles.push(Particle { position:[p[0],p[1],p[2]], velocity:[v[0],v[1],v[2]] });
+	for (position, velocity) in parse_lines(input, parse_particle)? {
+		particles.push(Particle { position, velocity });
 	}
+	bench::parsed();
 
 	// Relative to the moving rock, all particles will intersect same point
 	// Search for velocities in each plane that make all particles intersect a common point
@@ -123,12 +138,15 @@ fn main() {
 		if let Some((vyz, _tyz)) = find_velocity_2d(&particles, (1,2)) {
 			if let Some((_vzx, _tzx)) = find_velocity_2d(&particles, (2,0)) {
 				let vel = [vxy.0, vxy.1, vyz.1];
-				let col0 = particles[0].at(txy, 1).unwrap();
-				let pos = [col0[0] - txy * vel[0], col0[1] - txy * vel[1], col0[2] - txy * vel[2] ];
-				println!("vel:{vel:?} pos:{pos:?} sum:{}", pos[0] + pos[1] + pos[2]);
-				// 568386357876600
+				if let Some(col0) = particles[0].at(txy, 1) {
+					let pos = [col0[0] - txy * vel[0], col0[1] - txy * vel[1], col0[2] - txy * vel[2] ];
+//					println!("vel:{vel:?} pos:{pos:?} sum:{}", pos[0] + pos[1] + pos[2]);
+					return Ok((pos[0] + pos[1] + pos[2]).into());
+					// 568386357876600
+				}
 			}
 		}
 	}
 
+	Ok(Answer::Unsolved)
 }
@@ -0,0 +1,217 @@
+use std::collections::{HashMap, BinaryHeap};
+use rand::Rng;
+
+use crate::{bench, Answer, ParseError};
+use crate::graph_export::{Graph, Shape};
+use crate::search::{self, SearchState};
+
+#[derive(Debug, Clone)]
+struct Node {
+    edges: Vec<usize>
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Edge (usize, usize);
+
+impl Edge {
+    fn cross(&self, from: usize) -> usize {
+        if self.0 == from {
+            self.1
+        } else {
+            self.0
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Machine {
+    nodes: Vec<Node>,
+    edges: Vec<Edge>,
+	names: Vec<String>
+}
+
+// Search for a route through machine to 'end' - states are node indices
+struct Route<'a> {
+    machine: &'a Machine,
+    end: usize,
+}
+
+impl SearchState<Route<'_>> for usize {
+    fn successors(&self, route: &Route) -> Vec<(Self, usize)> {
+        let machine = route.machine;
+        machine.nodes[*self].edges.iter().map(|&e| (machine.edges[e].cross(*self), 1)).collect()
+    }
+
+    fn is_goal(&self, route: &Route) -> bool {
+        *self == route.end
+    }
+}
+
+fn add_node(map: &mut HashMap<String, usize>, nodes: &mut Vec<Node>, n:&str) -> usize {
+    *map.entry(n.to_string()).or_insert_with(|| {
+       nodes.push( Node { edges: Vec::new() });
+        nodes.len() -1
+    })
+}
+
+fn add_edge(map: &mut HashMap<Edge, usize>, edges: &mut Vec<Edge>, n0:usize, n1:usize) -> usize {
+    let e = Edge(usize::min(n0,n1), usize::max(n0,n1));
+    *map.entry(e).or_insert_with(|| {
+        edges.push(e);
+        edges.len()-1
+    })
+}
+
+impl Machine {
+    fn create(source: &[String]) -> Result<Self, ParseError> {
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut names = Vec::new();
+        let mut nodes_map: HashMap<_, usize> = HashMap::new();
+        let mut edges_map: HashMap<Edge, usize> = HashMap::new();
+
+        for (i, l) in source.iter().enumerate().filter(|(_, l)| !l.is_empty()) {
+            let colon = l.find(':').ok_or(ParseError::at(i + 1, 0, "Expected: <name>: <connections>"))?;
+            let (left, right) = l.split_at(colon);
+            let node_name = left.trim();
+            let connection_names = right
+                .trim_start_matches(":")
+                .split_whitespace()
+                .map(str::trim)
+                .collect::<Vec<_>>();
+
+            let n = add_node(&mut nodes_map, &mut nodes, node_name);
+            for cn in connection_names {
+                let c = add_node(&mut nodes_map, &mut nodes, cn);
+                let edge = add_edge(&mut edges_map, &mut edges, n, c);
+                nodes[n].edges.push(edge);
+                nodes[c].edges.push(edge);
+            }
+        }
+
+        names.resize(nodes.len(), "".to_string());
+        for (k,v) in nodes_map.into_iter() {
+            names[v] = k;
+        }
+        Ok(Self { nodes, edges, names })
+    }
+
+    // Undirected graph, with the edges in 'cut' highlighted
+    fn graph(&self, cut: &[usize]) -> Graph {
+        let mut graph = Graph::new(false);
+        for name in &self.names {
+            graph.add_node(name, Shape::Ellipse);
+        }
+        for (i, e) in self.edges.iter().enumerate() {
+            graph.add_edge(e.0, e.1, None, cut.contains(&i));
+        }
+        graph
+    }
+
+    // Edge joining two nodes
+    fn find_edge(&self, n0: usize, n1: usize) -> Option<usize> {
+        self.nodes[n0].edges.iter().copied().find(|&e| self.edges[e].cross(n0) == n1)
+    }
+
+    // Count the edges used by a shortest route from start to end
+    fn find_route(&self, start: usize, end:usize, edge_counts: &mut [usize]) {
+        let route = Route { machine: self, end };
+        if let Some(path) = search::bfs(&route, [start]) {
+            for (&n0, &n1) in path.states.iter().zip(&path.states[1..]) {
+                edge_counts[self.find_edge(n0, n1).unwrap()] += 1;
+            }
+        }
+    }
+
+    fn count_connected(&self, start: usize, hide_edges:&[bool])-> usize {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut pending : BinaryHeap<_> = BinaryHeap::new();
+        pending.push(start);
+
+        let mut count = 0;
+        while let Some(node) = pending.pop() {
+            if visited[node] {
+                continue;
+            }
+            visited[node] = true;
+            count +=1;
+
+            for &next_edge in &self.nodes[node].edges {
+                if !hide_edges[next_edge] {
+                    let next_node = self.edges[next_edge].cross(node);
+                    if !visited[next_node] {
+                        pending.push(next_node);
+                    }
+                }
+            }
+        }
+        count
+    }
+
+    // The three edges that routes between random pairs of nodes cross most often - the ones
+    // joining the two groups
+    fn find_cut(&self) -> Vec<usize> {
+        let mut edge_counts = vec![0; self.edges.len()];
+
+        // Need two nodes to route between
+        if self.nodes.len() < 2 {
+            return Vec::new();
+        }
+
+        let mut rng = rand::thread_rng();
+
+        for _ in 0 .. 10000 {
+            let start = rng.gen_range(0..self.nodes.len());
+            let mut end;
+            loop {
+                 end=rng.gen_range(0..self.nodes.len());
+                 if start != end {
+                    break;
+                 }
+            }
+            self.find_route(start, end, &mut edge_counts);
+        }
+
+        let mut edges_sorted = edge_counts.iter().enumerate().map(|(i,c)| (c,i)).collect::<Vec<_>>();
+        edges_sorted.sort();
+
+        let mut joining_edges = Vec::new();
+        for _ in 0..3 {
+            if let Some((_c,e)) = edges_sorted.pop() {
+//                println!(" Edge {e} {_c}");
+                joining_edges.push(e);
+            }
+        }
+        joining_edges
+    }
+}
+
+// Components, with the edges that join the two groups highlighted
+pub fn graph(input: &str) -> Result<Graph, ParseError> {
+    let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+    let machine = Machine::create(&lines)?;
+    Ok(machine.graph(&machine.find_cut()))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+    let machine = Machine::create(&lines)?;
+    bench::parsed();
+//    print!("{}", machine.graph(&[]).to_dot());
+
+    let joining_edges = machine.find_cut();
+    let mut hide_edges = vec![false; machine.edges.len()];
+    for &e in &joining_edges {
+        hide_edges[e] = true;
+    }
+
+    let Some(&cut) = joining_edges.first() else {
+        return Ok(Answer::Unsolved);
+    };
+    let group0 = machine.count_connected(machine.edges[cut].0, &hide_edges);
+    let group1 = machine.count_connected(machine.edges[cut].1, &hide_edges);
+
+//    println!("Nodes:{} Edges:{} Group0:{} Group1:{} Product:{}", machine.nodes.len(), machine.edges.len(), group0, group1, group0 * group1);
+    Ok((group0 * group1).into())
+    // 596376
+}
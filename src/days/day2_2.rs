@@ -0,0 +1,59 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
+
+#[derive(Debug)]
+struct Draw {
+    red: u32,
+    green: u32,
+    blue: u32,
+}
+
+// eg. "3 blue, 4 red"
+fn parse_draw(s: &str) -> Result<Draw, ParseError> {
+    let mut g = Draw{ red:0, green:0, blue:0 };
+
+    for ball in s.split(",").map(|s| s.trim()) {
+        let (num,col) = ball.split_once(' ')
+            .ok_or_else(|| ParseError::new("Expected: <number> <colour>").within(s, ball))?;
+        let n = parse_value::<u32>(num).map_err(|e| e.within(s, num))?;
+        match col.trim() {
+            "red" => g.red = n,
+            "green" => g.green = n,
+            "blue" => g.blue = n,
+            _ => ()
+        }
+    }
+
+    Ok(g)
+}
+
+// eg. "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+fn parse_game(l: &str) -> Result<(u32, Vec<Draw>), ParseError> {
+    let Some((game, draws)) = l.split_once(':') else {
+        return Err(ParseError::new("Expected: Game <id>: <draws>"));
+    };
+    let id = game.strip_prefix("Game ")
+        .ok_or_else(|| ParseError::new("Expected: Game <id>").with_column(1))
+        .and_then(|id| parse_value(id).map_err(|e| e.within(l, id)))?;
+    let draws = draws.split(';').map(|d| parse_draw(d).map_err(|e| e.within(l, d))).collect::<Result<Vec<_>, _>>()?;
+    Ok((id, draws))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let games = parse_lines(input, parse_game)?;
+    bench::parsed();
+
+    let mut total  = 0;
+    for (_, draws) in games {
+
+        let minballs = draws.iter().fold(Draw{red:0, green:0, blue:0}, |acc, d| Draw{
+                red: d.red.max(acc.red),
+                green: d.green.max(acc.green),
+                blue: d.blue.max(acc.blue) });
+
+        let power = minballs.red * minballs.green * minballs.blue;
+
+        total += power;
+    }
+    Ok(total.into())
+}
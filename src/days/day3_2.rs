@@ -1,6 +1,8 @@
-use std::fs;
 use regex::Regex;
 
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
+
 #[derive(Debug)]
 struct Part {
     number : u32,
@@ -14,13 +16,11 @@ fn check_number(part: &Part, x: usize, y: usize) -> bool {
     y >= part.y-1 && y <= part.y+1
 }
 
-fn main() {
-    println!("Hello, Day 3 2!");
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
     let re = Regex::new("[0-9]+").unwrap();
 
     // Get each line
-    let file = fs::read_to_string("inputs/day3").unwrap();
-    let lines = file.lines().collect::<Vec<_>>();
+    let lines = input.lines().collect::<Vec<_>>();
     let width = lines.iter().fold(0, |acc, l| acc.max(l.len()));
 
     // Convert to 2d array of chars with border padding of '.'
@@ -29,17 +29,20 @@ fn main() {
     plan.push(vec!['.';width+2]);
     for l in &lines {
 //        println!("- {l}");
-        let cs = [vec!['.';1], l.chars().collect::<Vec<_>>(), vec!['.';1]].concat();
+        // Short lines are padded out to the same width
+        let mut cs = [vec!['.';1], l.chars().collect::<Vec<_>>()].concat();
+        cs.resize(width+2, '.');
         plan.push(cs);
     }
     plan.push(vec!['.';width+2]);
+    bench::parsed();
 
     // Find the numbers in each line and add to vector
     let mut parts : Vec<Part> = vec![];
 
     for (y, l) in lines.iter().enumerate() {
         for m in re.find_iter(l) {
-            let number = m.as_str().parse::<u32>().unwrap();
+            let number = parse_value::<u32>(m.as_str()).map_err(|e| e.within(l, m.as_str()).with_line(y + 1))?;
             let (xs,xe) = (m.start()+1, m.end()+1);
             parts.push(Part { number, xs, xe, y:y+1 });
         }
@@ -59,5 +62,5 @@ fn main() {
         }
     }
 
-    println!("Total {}", total);
+    Ok(total.into())
 }
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
+
+fn parse_numbers(l: &str, s: &str) -> Result<HashSet<u32>, ParseError> {
+    s.split_whitespace().map(|n| parse_value(n).map_err(|e| e.within(l, n))).collect()
+}
+
+// eg. "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53" - winning and held numbers
+fn parse_card(l: &str) -> Result<(HashSet<u32>, HashSet<u32>), ParseError> {
+    let Some((_, numbers)) = l.split_once(':') else {
+        return Err(ParseError::new("Expected: Card <id>: <numbers> | <numbers>"));
+    };
+    let Some((win, have)) = numbers.split_once('|') else {
+        return Err(ParseError::new("Expected: '|'").within(l, numbers));
+    };
+    Ok((parse_numbers(l, win)?, parse_numbers(l, have)?))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let cards = parse_lines(input, parse_card)?;
+    bench::parsed();
+
+    let mut total = 0;
+
+    // For each card
+    for (win_numbers, have_numbers) in cards {
+
+        let winning = win_numbers.intersection(&have_numbers).collect::<Vec<_>>();
+        let points = if winning.is_empty() { 0 } else { 2u32.pow((winning.len()-1) as u32) };
+        total += points;
+//        println!("{win_numbers:?} {have_numbers:?} {winning:?} {points}");
+    }
+
+
+    Ok(total.into())
+}
@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
+
+#[derive(Debug)]
+struct Card {
+    wins: u32,
+    copies: u32,
+}
+
+fn parse_numbers(l: &str, s: &str) -> Result<HashSet<u32>, ParseError> {
+    s.split_whitespace().map(|n| parse_value(n).map_err(|e| e.within(l, n))).collect()
+}
+
+// eg. "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53" - winning and held numbers
+fn parse_card(l: &str) -> Result<(HashSet<u32>, HashSet<u32>), ParseError> {
+    let Some((_, numbers)) = l.split_once(':') else {
+        return Err(ParseError::new("Expected: Card <id>: <numbers> | <numbers>"));
+    };
+    let Some((win, have)) = numbers.split_once('|') else {
+        return Err(ParseError::new("Expected: '|'").within(l, numbers));
+    };
+    Ok((parse_numbers(l, win)?, parse_numbers(l, have)?))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut total = 0;
+
+    let mut cards: Vec<Card> = vec![];
+
+    // For each card, figure wins
+    for (win_numbers, have_numbers) in parse_lines(input, parse_card)? {
+
+        let wins = win_numbers.intersection(&have_numbers).count() as u32;
+
+        cards.push(Card{wins, copies:1});
+    }
+    bench::parsed();
+
+    for i in 0..cards.len() {
+        for w in 0..cards[i].wins {
+            let idx = i+1+(w as usize);
+            if idx < cards.len() {
+                cards[idx].copies += cards[i].copies;
+            }
+        }
+        total += cards[i].copies;
+    }
+
+    Ok(total.into())
+}
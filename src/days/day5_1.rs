@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
 
 #[derive(Debug)]
 struct Map {
@@ -8,19 +9,21 @@ struct Map {
 }
 
 impl Map {
-    fn from_input<I>(input: & mut I) -> Vec<Map> where I: Iterator<Item = String> {
+    // Read lines of (line number, text) up to the first line that is not all numbers
+    fn from_input<'a, I>(input: & mut I) -> Result<Vec<Map>, ParseError> where I: Iterator<Item = (usize, &'a str)> {
         let mut m = vec! [];
 
-        while let Some(l) = input.next() {
+        while let Some((line, l)) = input.next() {
             let n = l.split(' ').map(|s| s.parse::<usize>()).collect::<Result<Vec<_>,_>>();
-            if let Ok(nums) = n {
-                m.push(Map { destination_start: nums[0], source_start: nums[1], range_length: nums[2]})
-            } else {
-                break;
+            match n.as_deref() {
+                Ok(&[destination_start, source_start, range_length]) =>
+                    m.push(Map { destination_start, source_start, range_length }),
+                Ok(_) => return Err(ParseError::at(line, 1, "Expected: <destination> <source> <length>")),
+                Err(_) => break,
             }
         }
 
-        m
+        Ok(m)
     }
 
     fn convert(&self, seed:usize) -> Option<usize> {
@@ -42,14 +45,18 @@ fn convert_ranges(ranges : &Vec<Map>, value: usize) -> usize {
     return value;
 }
 
-fn main() {
-    println!("Hello, Day 5 1!");
-
-    // For each card, figure wins
-    let input = fs::read_to_string("inputs/day5").unwrap();
-    let mut input_iter = input.lines().map(|s| s.to_string());
-    let seeds = input_iter.next().unwrap()[7..].split(' ').
-        map(|s| s.parse::<usize>().unwrap()).collect::<Vec<_>>();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut input_iter = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let seeds_line = input_iter.next().map(|(_, l)| l).unwrap_or("");
+    let Some(seeds) = seeds_line.strip_prefix("seeds:") else {
+        return Err(ParseError::at(1, 1, "Expected: seeds: <numbers>"));
+    };
+    let seeds = seeds.split_whitespace()
+        .map(|s| parse_value::<usize>(s).map_err(|e| e.within(seeds_line, s).with_line(1)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::at(1, 1, "No seeds"));
+    }
 
     let mut seed_to_soil = vec! [];
     let mut fertilizer_to_water = vec! [];
@@ -59,18 +66,19 @@ fn main() {
     let mut humidity_to_location = vec! [];
     let mut soil_to_fertilizer = vec! [];
 
-    while let Some(r) = input_iter.next() {
-        match r.as_str() {
-                "seed-to-soil map:" => seed_to_soil = Map::from_input(&mut input_iter),
-                "soil-to-fertilizer map:" => soil_to_fertilizer = Map::from_input(&mut input_iter),
-                "fertilizer-to-water map:" => fertilizer_to_water = Map::from_input(&mut input_iter),
-                "water-to-light map:" => water_to_light = Map::from_input(&mut input_iter),
-                "light-to-temperature map:" => light_to_temperature = Map::from_input(&mut input_iter),
-                "temperature-to-humidity map:" => temperature_to_humidity = Map::from_input(&mut input_iter),
-                "humidity-to-location map:" => humidity_to_location = Map::from_input(&mut input_iter),
+    while let Some((_, r)) = input_iter.next() {
+        match r {
+                "seed-to-soil map:" => seed_to_soil = Map::from_input(&mut input_iter)?,
+                "soil-to-fertilizer map:" => soil_to_fertilizer = Map::from_input(&mut input_iter)?,
+                "fertilizer-to-water map:" => fertilizer_to_water = Map::from_input(&mut input_iter)?,
+                "water-to-light map:" => water_to_light = Map::from_input(&mut input_iter)?,
+                "light-to-temperature map:" => light_to_temperature = Map::from_input(&mut input_iter)?,
+                "temperature-to-humidity map:" => temperature_to_humidity = Map::from_input(&mut input_iter)?,
+                "humidity-to-location map:" => humidity_to_location = Map::from_input(&mut input_iter)?,
                 _ => (),
         }
     }
+    bench::parsed();
 
     let mut nearest = usize::MAX;
     for seed in seeds {
@@ -87,5 +95,5 @@ fn main() {
             nearest = location
         }
     }
-    println!("Nearest {nearest}");
+    Ok(nearest.into())
 }
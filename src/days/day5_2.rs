@@ -1,4 +1,5 @@
-use std::fs;
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
 //use itertools::Itertools;
 
 #[derive(Debug)]
@@ -15,19 +16,21 @@ struct Range {
 }
 
 impl Map {
-    fn from_input<I>(input: & mut I) -> Vec<Map> where I: Iterator<Item = String> {
+    // Read lines of (line number, text) up to the first line that is not all numbers
+    fn from_input<'a, I>(input: & mut I) -> Result<Vec<Map>, ParseError> where I: Iterator<Item = (usize, &'a str)> {
         let mut m = vec! [];
 
-        while let Some(l) = input.next() {
+        while let Some((line, l)) = input.next() {
             let n = l.split(' ').map(|s| s.parse::<usize>()).collect::<Result<Vec<_>,_>>();
-            if let Ok(nums) = n {
-                m.push(Map { destination_start: nums[0], source_start: nums[1], range_length: nums[2]})
-            } else {
-                break;
+            match n.as_deref() {
+                Ok(&[destination_start, source_start, range_length]) =>
+                    m.push(Map { destination_start, source_start, range_length }),
+                Ok(_) => return Err(ParseError::at(line, 1, "Expected: <destination> <source> <length>")),
+                Err(_) => break,
             }
         }
 
-        m
+        Ok(m)
     }
 
     fn convert(&self, seeds:&Range, mapped: &mut Vec<Range>, unmapped: &mut Vec<Range>) {
@@ -76,14 +79,18 @@ fn convert_ranges(ranges : &Vec<Map>, seeds: &Vec<Range>) -> Vec<Range> {
     return output;
 }
 
-fn main() {
-    println!("Hello, Day 5 2!");
-
-    // For each card, figure wins
-    let input = fs::read_to_string("inputs/day5").unwrap();
-    let mut input_iter = input.lines().map(|s| s.to_string());
-    let seeds = input_iter.next().unwrap()[7..].split(' ').
-        map(|s| s.parse::<usize>().unwrap()).collect::<Vec<_>>();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut input_iter = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+    let seeds_line = input_iter.next().map(|(_, l)| l).unwrap_or("");
+    let Some(seeds) = seeds_line.strip_prefix("seeds:") else {
+        return Err(ParseError::at(1, 1, "Expected: seeds: <numbers>"));
+    };
+    let seeds = seeds.split_whitespace()
+        .map(|s| parse_value::<usize>(s).map_err(|e| e.within(seeds_line, s).with_line(1)))
+        .collect::<Result<Vec<_>, _>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::at(1, 1, "No seeds"));
+    }
 
     let mut seed_to_soil = vec! [];
     let mut fertilizer_to_water = vec! [];
@@ -93,18 +100,19 @@ fn main() {
     let mut humidity_to_location = vec! [];
     let mut soil_to_fertilizer = vec! [];
 
-    while let Some(r) = input_iter.next() {
-        match r.as_str() {
-                "seed-to-soil map:" => seed_to_soil = Map::from_input(&mut input_iter),
-                "soil-to-fertilizer map:" => soil_to_fertilizer = Map::from_input(&mut input_iter),
-                "fertilizer-to-water map:" => fertilizer_to_water = Map::from_input(&mut input_iter),
-                "water-to-light map:" => water_to_light = Map::from_input(&mut input_iter),
-                "light-to-temperature map:" => light_to_temperature = Map::from_input(&mut input_iter),
-                "temperature-to-humidity map:" => temperature_to_humidity = Map::from_input(&mut input_iter),
-                "humidity-to-location map:" => humidity_to_location = Map::from_input(&mut input_iter),
+    while let Some((_, r)) = input_iter.next() {
+        match r {
+                "seed-to-soil map:" => seed_to_soil = Map::from_input(&mut input_iter)?,
+                "soil-to-fertilizer map:" => soil_to_fertilizer = Map::from_input(&mut input_iter)?,
+                "fertilizer-to-water map:" => fertilizer_to_water = Map::from_input(&mut input_iter)?,
+                "water-to-light map:" => water_to_light = Map::from_input(&mut input_iter)?,
+                "light-to-temperature map:" => light_to_temperature = Map::from_input(&mut input_iter)?,
+                "temperature-to-humidity map:" => temperature_to_humidity = Map::from_input(&mut input_iter)?,
+                "humidity-to-location map:" => humidity_to_location = Map::from_input(&mut input_iter)?,
                 _ => (),
         }
     }
+    bench::parsed();
 
     let mut seed_ranges = vec![];
     let mut seeds_iter = seeds.into_iter();
@@ -117,7 +125,7 @@ fn main() {
         }
         break;
     }
-    println!("Seeds: {seed_ranges:?}");
+//    println!("Seeds: {seed_ranges:?}");
 
     let soil = convert_ranges(&seed_to_soil, &seed_ranges);
     let fertilizer = convert_ranges(&soil_to_fertilizer, &soil);
@@ -127,7 +135,7 @@ fn main() {
     let humidity = convert_ranges(&temperature_to_humidity, &temperature);
     let location = convert_ranges(&humidity_to_location, &humidity);
 
-    println!("Location: {}", location[0].start);
+    Ok(location[0].start.into())
 }
 
 #[test]
@@ -139,9 +147,9 @@ fn test1() {
     assert_eq!(convert_ranges(&map, &vec![Range { start: 101, length: 5}]), vec![Range { start: 1001, length: 5 } ]);
     assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 5}]), vec![Range { start: 90, length: 5 } ]);
     assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 10}]), vec![Range { start: 90, length: 10 } ]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 11}]), vec![Range { start: 1000, length: 1 }, Range { start: 90, length: 10 }]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 109, length: 2}]), vec![Range { start: 1009, length: 1 }, Range { start: 110, length: 1 }]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 32}]), vec![Range { start: 1000, length: 10 }, Range { start: 90, length: 10 }, Range { start: 110, length: 12 }]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 11}]), vec![Range { start: 90, length: 10 }, Range { start: 1000, length: 1 }]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 109, length: 2}]), vec![Range { start: 110, length: 1 }, Range { start: 1009, length: 1 }]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 32}]), vec![Range { start: 90, length: 10 }, Range { start: 110, length: 12 }, Range { start: 1000, length: 10 }]);
 }
 
 #[test]
@@ -153,7 +161,7 @@ fn test2() {
 
     assert_eq!(convert_ranges(&map, &vec![Range { start: 101, length: 5}]), vec![Range { start: 1001, length: 5 } ]);
     assert_eq!(convert_ranges(&map, &vec![Range { start: 121, length: 7}]), vec![Range { start: 2001, length: 7 } ]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 32} ]), vec![Range { start: 1000, length: 10 }, Range { start: 2000, length: 2 }, Range { start: 90, length: 10 }, Range { start: 110, length: 10 }]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 82} ]), vec![Range { start: 1000, length: 10 }, Range { start: 2000, length: 32 }, Range { start: 90, length: 10 }, Range { start: 110, length: 10 }, Range { start: 152, length: 20 }]);
-    assert_eq!(convert_ranges(&map, &vec![Range { start: 101, length: 7}, Range { start: 121, length: 7}]), vec![Range { start: 2001, length: 7 } ]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 32} ]), vec![Range { start: 90, length: 10 }, Range { start: 110, length: 10 }, Range { start: 1000, length: 10 }, Range { start: 2000, length: 2 }]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 90, length: 82} ]), vec![Range { start: 90, length: 10 }, Range { start: 110, length: 10 }, Range { start: 152, length: 20 }, Range { start: 1000, length: 10 }, Range { start: 2000, length: 32 }]);
+    assert_eq!(convert_ranges(&map, &vec![Range { start: 101, length: 7}, Range { start: 121, length: 7}]), vec![Range { start: 1001, length: 7 }, Range { start: 2001, length: 7 } ]);
 }
@@ -0,0 +1,62 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
+
+
+fn distance(race_time: i64, button_time:i64) -> i64
+{
+    button_time * race_time - button_time * button_time
+}
+
+fn max_distance(race_time: i64, button_time:i64) -> i64
+{
+    // distance = r * b - b * b
+    // d(b) = r - 2b
+    // max =  b/2
+    return race_time / 2;
+}
+
+// Numbers following 'label' on line 'n' of input
+fn parse_row(input: &str, n: usize, label: &str) -> Result<Vec<i64>, ParseError> {
+    let l = input.lines().nth(n - 1).unwrap_or("");
+    let Some(numbers) = l.strip_prefix(label) else {
+        return Err(ParseError::at(n, 1, format!("Expected: {label} <numbers>")));
+    };
+    numbers.split_whitespace().map(|s| parse_value(s).map_err(|e| e.within(l, s).with_line(n))).collect()
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let times = parse_row(input, 1, "Time:")?;
+    let distances = parse_row(input, 2, "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(ParseError::at(2, 1, "Expected: same number of times and distances"));
+    }
+    bench::parsed();
+
+//    println!("{times:?}");
+//    println!("{distances:?}");
+
+    let mut wins_product = 1;
+    for (race_time,race_best_d) in times.into_iter().zip(distances.into_iter()) {
+//        println!("{t} {d}");
+        let mut wins =0;
+        for b in 0..=race_time {
+            let d = distance(race_time, b);
+            if d > race_best_d {
+                wins += 1
+            }
+        }
+//        println!("Wins:{wins}");
+        wins_product *= wins;
+    }
+
+    Ok(wins_product.into())
+}
+
+#[test]
+fn test1() {
+    assert_eq!(distance(7, 1), 6);
+    assert_eq!(distance(7, 2), 10);
+    assert_eq!(distance(7, 3), 12);
+    assert_eq!(distance(7, 4), 12);
+    assert_eq!(distance(7, 7), 0);
+}
@@ -0,0 +1,74 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::parse_value;
+
+
+fn race_distance(race_time: i64, button_time:i64) -> i64
+{
+    button_time * race_time - button_time * button_time
+}
+
+fn isqrt(x: i64) -> i64
+{
+    (x as f64).sqrt() as i64
+}
+
+fn solve_win(race_time: i64, race_distance:i64) -> (i64,i64)
+{
+    // Solve quadratic
+    let a = -1;
+    let b = race_time;
+    let c = -race_distance;
+
+    let r = isqrt(b*b - 4*a*c);
+    ((-b + r ) / 2 * a, (-b - r )/ 2 * a)
+}
+
+fn max_distance(race_time: i64, button_time:i64) -> i64
+{
+    // distance = r * b - b * b
+    // d(b) = r - 2b
+    // max =  b/2
+    return race_time / 2;
+}
+
+// Number following 'label' on line 'n' of input, ignoring spaces
+fn parse_row(input: &str, n: usize, label: &str) -> Result<i64, ParseError> {
+    let l = input.lines().nth(n - 1).unwrap_or("");
+    let Some(number) = l.strip_prefix(label) else {
+        return Err(ParseError::at(n, 1, format!("Expected: {label} <number>")));
+    };
+    parse_value(&number.chars().filter(|c| !c.is_whitespace()).collect::<String>())
+        .map_err(|e| e.within(l, number).with_line(n))
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let time = parse_row(input, 1, "Time:")?;
+    let distance = parse_row(input, 2, "Distance:")?;
+    bench::parsed();
+
+//    println!("{time:?}");
+//    println!("{distance:?}");
+
+    let (mut b1, mut b2) = solve_win(time, distance);
+
+    // Fix up rounding
+    while race_distance(time, b1) < distance {
+        b1 += 1;
+    }
+
+    while race_distance(time, b2) < distance {
+        b2 -= 1;
+    }
+
+    let wins = b2 - b1 + 1;
+    Ok(wins.into())
+}
+
+#[test]
+fn test1() {
+    assert_eq!(race_distance(7, 1), 6);
+    assert_eq!(race_distance(7, 2), 10);
+    assert_eq!(race_distance(7, 3), 12);
+    assert_eq!(race_distance(7, 4), 12);
+    assert_eq!(race_distance(7, 7), 0);
+}
@@ -1,5 +1,7 @@
-use std::{fs, collections::HashMap};
-use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum HandType {
@@ -90,17 +92,25 @@ struct Hand {
     bid: usize
 }
 
-fn main() {
-    println!("Hello, Day 7 1!");
-
-    // For each card, figure wins
-    let input = fs::read_to_string("inputs/day7").unwrap();
+// eg. "32T3K 765"
+fn parse_hand(l: &str) -> Result<Hand, ParseError> {
+    let Some((cards, bid)) = l.split_once(' ') else {
+        return Err(ParseError::new("Expected: <cards> <bid>"));
+    };
+    let cs = cards.chars().map(card_from_char).collect::<Vec<_>>();
+    if let Some(i) = cs.iter().position(|&c| c == Card::None) {
+        return Err(ParseError::at(0, i + 1, "Bad card"));
+    }
+    let Ok(cs) = <[Card;5]>::try_from(cs) else {
+        return Err(ParseError::at(0, 1, "Expected: 5 cards"));
+    };
+    let bid = parse_value::<usize>(bid).map_err(|e| e.within(l, bid))?;
+    Ok(Hand { cards:cs, typ:hand_type(cards), bid })
+}
 
-    let mut hands = input.lines().map(|l| {
-        let (cards, bid) = l.split_whitespace().collect_tuple().unwrap();
-        let cs = cards.chars().map(card_from_char).collect::<Vec<_>>();
-        Hand { cards:[cs[0],cs[1],cs[2],cs[3],cs[4]], typ:hand_type(cards), bid:bid.parse::<usize>().unwrap() }
-    }).collect::<Vec<_>>();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut hands = parse_lines(input, parse_hand)?;
+    bench::parsed();
 
     hands.sort();
 
@@ -111,7 +121,7 @@ fn main() {
         total += h.bid * rank;
     };
 
-    println!("Winnings: {total}")
+    Ok(total.into())
 }
 
 #[test]
@@ -1,5 +1,7 @@
-use std::{fs, collections::HashMap};
-use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
 
 #[derive(Debug, PartialEq, PartialOrd, Eq, Ord)]
 enum HandType {
@@ -121,17 +123,25 @@ struct Hand {
     bid: usize
 }
 
-fn main() {
-    println!("Hello, Day 7 2!");
-
-    // For each card, figure wins
-    let input = fs::read_to_string("inputs/day7").unwrap();
+// eg. "32T3K 765"
+fn parse_hand(l: &str) -> Result<Hand, ParseError> {
+    let Some((cards, bid)) = l.split_once(' ') else {
+        return Err(ParseError::new("Expected: <cards> <bid>"));
+    };
+    let cs = cards.chars().map(card_from_char).collect::<Vec<_>>();
+    if let Some(i) = cs.iter().position(|&c| c == Card::None) {
+        return Err(ParseError::at(0, i + 1, "Bad card"));
+    }
+    let Ok(cs) = <[Card;5]>::try_from(cs) else {
+        return Err(ParseError::at(0, 1, "Expected: 5 cards"));
+    };
+    let bid = parse_value::<usize>(bid).map_err(|e| e.within(l, bid))?;
+    Ok(Hand { cards:cs, typ:hand_type(&cs), bid })
+}
 
-    let mut hands = input.lines().map(|l| {
-        let (cards, bid) = l.split_whitespace().collect_tuple().unwrap();
-        let cs = cards.chars().map(card_from_char).collect::<Vec<_>>();
-        Hand { cards:[cs[0],cs[1],cs[2],cs[3],cs[4]], typ:hand_type(&cs), bid:bid.parse::<usize>().unwrap() }
-    }).collect::<Vec<_>>();
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut hands = parse_lines(input, parse_hand)?;
+    bench::parsed();
 
     hands.sort();
 
@@ -141,7 +151,7 @@ fn main() {
         total += h.bid * rank;
     };
 
-    println!("Winnings: {total}")
+    Ok(total.into())
     //243101568
 }
 
@@ -152,19 +162,20 @@ fn to_cards(s: &str) -> Vec<Card> {
 
 #[test]
 fn test1() {
-    assert!(try_type(&vec![(Card::Ace, 5)], 0, vec![5]));
-    assert!(try_type(&vec![(Card::Ace, 4)], 1, vec![5]));
-    assert!(try_type(&vec![(Card::Ace, 1)], 4, vec![5]));
-    assert!(try_type(&vec![], 5, vec![5]));
-    assert!(!try_type(&vec![(Card::Ace, 3),(Card::Two, 2)], 0, vec![5]));
-    assert!(!try_type(&vec![(Card::Ace, 3),(Card::Two, 2)], 0, vec![4]));
-    assert!(try_type(&vec![(Card::Ace, 2),(Card::Two, 3)], 0, vec![3]));
-    assert!(try_type(&vec![(Card::Ace, 2),(Card::Two, 3)], 0, vec![3,2]));
-    assert!(!try_type(&vec![(Card::Ace, 2),(Card::Two, 3)], 0, vec![3,3]));
-    assert!(try_type(&vec![(Card::Ace, 1),(Card::Two, 2)], 2, vec![3,2]));
-    assert!(try_type(&vec![(Card::Ace, 1),(Card::Two, 2)], 2, vec![2,2]));
-    assert!(try_type(&vec![(Card::Ace, 1),(Card::Two, 1)], 2, vec![2,2]));
-    assert!(try_type(&vec![(Card::Ace, 1)], 3, vec![2,2]));
+    // Card counts in ascending order
+    assert!(try_type(&[5], 0, &[5]));
+    assert!(try_type(&[4], 1, &[5]));
+    assert!(try_type(&[1], 4, &[5]));
+    assert!(try_type(&[], 5, &[5]));
+    assert!(!try_type(&[2,3], 0, &[5]));
+    assert!(!try_type(&[2,3], 0, &[4]));
+    assert!(try_type(&[2,3], 0, &[3]));
+    assert!(try_type(&[2,3], 0, &[3,2]));
+    assert!(!try_type(&[2,3], 0, &[3,3]));
+    assert!(try_type(&[1,2], 2, &[3,2]));
+    assert!(try_type(&[1,2], 2, &[2,2]));
+    assert!(try_type(&[1,1], 2, &[2,2]));
+    assert!(try_type(&[1], 3, &[2,2]));
 
     assert_eq!(hand_type(&to_cards("AAAAA")), HandType::FiveOfAKind);
     assert_eq!(hand_type(&to_cards("AA8AA")), HandType::FourOfAKind);
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use crate::{bench, Answer, ParseError};
+
+#[derive(Debug, Clone)]
+struct Node {
+    left: usize,
+    right: usize,
+}
+
+// Read lines of (line number, text) as "AAA = (BBB, CCC)"
+fn graph_from_input<'a, I>(input: &mut I) -> Result<Vec<Node>, ParseError> where I: Iterator<Item = (usize, &'a str)> {
+
+    let mut names = HashMap::new();
+
+    names.insert("AAA".to_string(), 1);
+    names.insert("ZZZ".to_string(), 2);
+    let mut last_id = 2;
+
+    let mut graph = Vec::new();
+    let mut defined = vec![false; 3];
+
+    // XXX sort out string borrowing
+    for (line, l) in input.filter(|(_, s)| !s.is_empty()) {
+        let c = l.split(|c:char| !c.is_alphanumeric()).filter(|s| !s.is_empty()).map(|s| s.to_string()).collect::<Vec<_>>();
+        let [node,left,right] = <[String;3]>::try_from(c)
+            .map_err(|_| ParseError::at(line, 1, "Expected: <node> = (<left>, <right>)"))?;
+
+//        println!(":{node} {left} {right}");
+        let node_id = *names.entry(node).or_insert_with(|| { last_id +=1; last_id });
+        let left_id = *names.entry(left).or_insert_with(|| { last_id +=1; last_id });
+        let right_id = *names.entry(right).or_insert_with(|| { last_id +=1; last_id });
+
+//        println!("{node_id} {left_id} {right_id}");
+        graph.resize(last_id+1, Node{left:0, right:0});
+        defined.resize(last_id+1, false);
+        graph[node_id] = Node { left:left_id, right: right_id};
+        defined[node_id] = true;
+    }
+
+    // Every node that can be reached must lead somewhere
+    if let Some((name, _)) = names.iter().find(|(_, &id)| !defined[id]) {
+        return Err(ParseError::new(format!("Unknown node: {name}")));
+    }
+
+    Ok(graph)
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut input_iter = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+    let instructions = input_iter.next().map(|(_, l)| l).unwrap_or("");
+    if let Some(i) = instructions.chars().position(|c| c != 'L' && c != 'R') {
+        return Err(ParseError::at(1, i + 1, "Unknown direction"));
+    }
+//    println!("Instructions: {instructions}");
+
+    let graph = graph_from_input(&mut input_iter)?;
+    bench::parsed();
+
+    let mut node = 1;
+    let mut count = 0;
+    for direction in instructions.chars().cycle() {
+        count += 1;
+        match(direction) {
+            'L' => node = graph[node].left,
+            'R' => node = graph[node].right,
+            _ => unreachable!(),
+        }
+
+        if node == 2 {
+            break;
+        }
+
+    }
+    Ok(count.into())
+}
+
+
+#[test]
+fn test1() {
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use crate::{bench, Answer, ParseError};
+
+#[derive(Debug, Clone)]
+struct Node {
+    left: usize,
+    right: usize,
+    startflag: bool,
+    endflag: bool,
+}
+
+// Read lines of (line number, text) as "11A = (11B, XXX)"
+fn graph_from_input<'a, I>(input: &mut I) -> Result<Vec<Node>, ParseError> where I: Iterator<Item = (usize, &'a str)> {
+
+    let mut names = HashMap::new();
+
+    let mut last_id = 0;
+
+    let mut graph = Vec::new();
+    let mut defined = vec![false];
+
+    // XXX sort out string borrowing
+    for (line, l) in input.filter(|(_, s)| !s.is_empty()) {
+        let c = l.split(|c:char| !c.is_alphanumeric()).filter(|s| !s.is_empty()).map(|s| s.to_string()).collect::<Vec<_>>();
+        let [nodename,leftname,rightname] = <[String;3]>::try_from(c)
+            .map_err(|_| ParseError::at(line, 1, "Expected: <node> = (<left>, <right>)"))?;
+
+        let node = *names.entry(nodename.clone()).or_insert_with(|| { last_id +=1; last_id });
+        let left = *names.entry(leftname.clone()).or_insert_with(|| { last_id +=1; last_id });
+        let right = *names.entry(rightname.clone()).or_insert_with(|| { last_id +=1; last_id });
+        let startflag = nodename.clone().ends_with('A');
+        let endflag = nodename.ends_with('Z');
+
+        graph.resize(last_id+1, Node{left:0, right:0, startflag:false, endflag:false});
+        graph[node] = Node { left, right, startflag, endflag};
+        defined.resize(last_id+1, false);
+        defined[node] = true;
+    }
+
+    // Every node that can be reached must lead somewhere
+    if let Some((name, _)) = names.iter().find(|(_, &id)| !defined[id]) {
+        return Err(ParseError::new(format!("Unknown node: {name}")));
+    }
+
+    Ok(graph)
+}
+
+fn distance(graph: &Vec<Node>, directions:&[bool], start: usize) -> (usize, usize) {
+
+    let mut node = start;
+    let mut count = 0;
+
+    for d in directions.iter().cycle() {
+        count += 1;
+        node =  if *d { graph[node].left } else { graph[node].right };
+        if graph[node].endflag {
+            break
+        }
+    }
+    (node, count)
+}
+
+pub fn solve(input: &str) -> Result<Answer, ParseError> {
+    let mut input_iter = input.lines().enumerate().map(|(i, l)| (i + 1, l));
+
+    let is = input_iter.next().map(|(_, l)| l).unwrap_or("");
+    let instructions = is.chars().enumerate().map(|(i, c)| {
+        match c {
+            'L' => Ok(true),
+            'R' => Ok(false),
+            _ => Err(ParseError::at(1, i + 1, "Unknown direction"))
+        }
+    }).collect::<Result<Vec<_>, _>>()?;
+
+    let graph = graph_from_input(&mut input_iter)?;
+    bench::parsed();
+
+    // Fnd starts and compute distances for each cycle
+    let starts = graph.iter().enumerate().filter(|(_,n)| n.startflag).map(|(i,_)| i);
+    let distances =
+        starts.map(|node| {let (_,d) = distance(&graph, &instructions, node); d}).collect::<Vec<_>>();
+//    println!("Distances: {distances:?}");
+
+    let steps = lcm(&distances);
+
+    Ok(steps.into())
+    // 18024643846273
+}
+
+// None if there are no numbers, or the result does not fit
+pub fn lcm(nums: &[usize]) -> Option<usize> {
+    let (&a, rest) = nums.split_first()?;
+    if rest.is_empty() {
+        return Some(a);
+    }
+    let b = lcm(rest)?;
+    match gcd_of_two_numbers(a, b) {
+        0 => Some(0),
+        gcd => (a / gcd).checked_mul(b),
+    }
+}
+
+fn gcd_of_two_numbers(a: usize, b: usize) -> usize {
+    if b == 0 {
+        return a;
+    }
+    gcd_of_two_numbers(b, a % b)
+}
+
+#[test]
+fn test1() {
+    assert_eq!(lcm(&[1, 2, 3, 4, 5]), Some(60));
+    assert_eq!(lcm(&[2, 4, 6, 8, 10]), Some(120));
+    assert_eq!(lcm(&[3, 6, 9, 12, 15]), Some(180));
+    assert_eq!(lcm(&[10]), Some(10));
+    assert_eq!(lcm(&[21, 110]), Some(2310));
+    assert_eq!(lcm(&[]), None);
+    assert_eq!(lcm(&[usize::MAX / 2, usize::MAX / 2]), Some(usize::MAX / 2));
+    assert_eq!(lcm(&[usize::MAX / 2, 4]), None);
+}
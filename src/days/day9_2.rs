@@ -0,0 +1,45 @@
+use crate::{bench, Answer, ParseError};
+use crate::error::{parse_lines, parse_value};
+
+pub fn solve(input: &str) -> Result<Answer, ParseError>
+{
+	let measurements = parse_lines(input, parse_measurements)?;
+	bench::parsed();
+
+	let mut sum = 0;
+	for mut m in measurements {
+		m.reverse();
+		let next = find_next(&m);
+//		println!("{m:?} {next}");
+		sum += next;
+	}
+
+	Ok(sum.into())
+}
+
+fn parse_measurements(text:&str) -> Result<Vec<i64>, ParseError>
+{
+	let m = text.split_whitespace().map(|s| parse_value(s).map_err(|e| e.within(text, s))).collect::<Result<Vec<_>, _>>()?;
+	if m.is_empty() {
+		return Err(ParseError::new("No measurements"));
+	}
+	Ok(m)
+}
+
+fn find_next(measurements: &[i64]) -> i64 {
+	let l = measurements.len();
+	let mut deltas = vec![0; l * l];
+	for n in 0..l {
+		deltas[n] = measurements[n]
+	}
+	let mut next = measurements[l-1];
+
+	for m in 1..(l-1) {
+		for n in 0..(l-m) {
+			deltas[m*l+n] = deltas[(m-1)*l+n+1] - deltas[(m-1)*l+n];
+		}
+		next += deltas[m*l+(l-m-1)];
+	}
+
+	next
+}
\ No newline at end of file
@@ -0,0 +1,140 @@
+use crate::{Answer, ParseError};
+
+pub mod day1_1;
+pub mod day1_2;
+pub mod day2_1;
+pub mod day2_2;
+pub mod day3_1;
+pub mod day3_2;
+pub mod day4_1;
+pub mod day4_2;
+pub mod day5_1;
+pub mod day5_2;
+pub mod day6_1;
+pub mod day6_2;
+pub mod day7_1;
+pub mod day7_2;
+pub mod day8_1;
+pub mod day8_2;
+pub mod day9_1;
+pub mod day9_2;
+pub mod day10_1;
+pub mod day10_2;
+pub mod day11_1;
+pub mod day11_2;
+pub mod day12_1;
+pub mod day12_2;
+pub mod day12_2a;
+pub mod day13_1;
+pub mod day13_2;
+pub mod day14;
+pub mod day14_1;
+pub mod day14_2;
+pub mod day15_1;
+pub mod day15_2;
+pub mod day16;
+pub mod day16_1;
+pub mod day16_2;
+pub mod day17;
+pub mod day17_1;
+pub mod day17_2;
+pub mod day18_1;
+pub mod day18_2;
+pub mod day19;
+pub mod day19_1;
+pub mod day19_2;
+pub mod day19_2a;
+pub mod day20_1;
+pub mod day20_2;
+pub mod day21_1;
+pub mod day21_2;
+pub mod day22_1;
+pub mod day22_2;
+pub mod day23;
+pub mod day23_1;
+pub mod day23_2;
+pub mod day24_1;
+pub mod day24_2;
+pub mod day25_1;
+
+// One registered day/part solver
+#[derive(Debug, Clone, Copy)]
+pub struct Solution {
+    pub name: &'static str,
+    pub day: u32,
+    pub part: u32,
+    pub solve: fn(&str) -> Result<Answer, ParseError>,
+}
+
+impl Solution {
+    // Default puzzle input for this day
+    pub fn input_path(&self) -> String {
+        format!("inputs/day{}", self.day)
+    }
+}
+
+// All solvers - where there is more than one for a day/part, the first is the default
+pub const SOLUTIONS: &[Solution] = &[
+    Solution { name: "day1_1", day: 1, part: 1, solve: day1_1::solve },
+    Solution { name: "day1_2", day: 1, part: 2, solve: day1_2::solve },
+    Solution { name: "day2_1", day: 2, part: 1, solve: day2_1::solve },
+    Solution { name: "day2_2", day: 2, part: 2, solve: day2_2::solve },
+    Solution { name: "day3_1", day: 3, part: 1, solve: day3_1::solve },
+    Solution { name: "day3_2", day: 3, part: 2, solve: day3_2::solve },
+    Solution { name: "day4_1", day: 4, part: 1, solve: day4_1::solve },
+    Solution { name: "day4_2", day: 4, part: 2, solve: day4_2::solve },
+    Solution { name: "day5_1", day: 5, part: 1, solve: day5_1::solve },
+    Solution { name: "day5_2", day: 5, part: 2, solve: day5_2::solve },
+    Solution { name: "day6_1", day: 6, part: 1, solve: day6_1::solve },
+    Solution { name: "day6_2", day: 6, part: 2, solve: day6_2::solve },
+    Solution { name: "day7_1", day: 7, part: 1, solve: day7_1::solve },
+    Solution { name: "day7_2", day: 7, part: 2, solve: day7_2::solve },
+    Solution { name: "day8_1", day: 8, part: 1, solve: day8_1::solve },
+    Solution { name: "day8_2", day: 8, part: 2, solve: day8_2::solve },
+    Solution { name: "day9_1", day: 9, part: 1, solve: day9_1::solve },
+    Solution { name: "day9_2", day: 9, part: 2, solve: day9_2::solve },
+    Solution { name: "day10_1", day: 10, part: 1, solve: day10_1::solve },
+    Solution { name: "day10_2", day: 10, part: 2, solve: day10_2::solve },
+    Solution { name: "day11_1", day: 11, part: 1, solve: day11_1::solve },
+    Solution { name: "day11_2", day: 11, part: 2, solve: day11_2::solve },
+    Solution { name: "day12_1", day: 12, part: 1, solve: day12_1::solve },
+    // Recursive counting checked against enumeration, on part 1 input
+    Solution { name: "day12_2a", day: 12, part: 1, solve: day12_2a::solve },
+    Solution { name: "day12_2", day: 12, part: 2, solve: day12_2::solve },
+    Solution { name: "day13_1", day: 13, part: 1, solve: day13_1::solve },
+    Solution { name: "day13_2", day: 13, part: 2, solve: day13_2::solve },
+    Solution { name: "day14_1", day: 14, part: 1, solve: day14_1::solve },
+    Solution { name: "day14_2", day: 14, part: 2, solve: day14_2::solve },
+    Solution { name: "day15_1", day: 15, part: 1, solve: day15_1::solve },
+    Solution { name: "day15_2", day: 15, part: 2, solve: day15_2::solve },
+    Solution { name: "day16_1", day: 16, part: 1, solve: day16_1::solve },
+    Solution { name: "day16_2", day: 16, part: 2, solve: day16_2::solve },
+    Solution { name: "day17_1", day: 17, part: 1, solve: day17_1::solve },
+    Solution { name: "day17_2", day: 17, part: 2, solve: day17_2::solve },
+    Solution { name: "day18_1", day: 18, part: 1, solve: day18_1::solve },
+    Solution { name: "day18_2", day: 18, part: 2, solve: day18_2::solve },
+    Solution { name: "day19_1", day: 19, part: 1, solve: day19_1::solve },
+    Solution { name: "day19_2", day: 19, part: 2, solve: day19_2::solve },
+    Solution { name: "day19_2a", day: 19, part: 2, solve: day19_2a::solve },
+    Solution { name: "day20_1", day: 20, part: 1, solve: day20_1::solve },
+    Solution { name: "day20_2", day: 20, part: 2, solve: day20_2::solve },
+    Solution { name: "day21_1", day: 21, part: 1, solve: day21_1::solve },
+    Solution { name: "day21_2", day: 21, part: 2, solve: day21_2::solve },
+    Solution { name: "day22_1", day: 22, part: 1, solve: day22_1::solve },
+    Solution { name: "day22_2", day: 22, part: 2, solve: day22_2::solve },
+    Solution { name: "day23_1", day: 23, part: 1, solve: day23_1::solve },
+    Solution { name: "day23_2", day: 23, part: 2, solve: day23_2::solve },
+    Solution { name: "day24_1", day: 24, part: 1, solve: day24_1::solve },
+    Solution { name: "day24_2", day: 24, part: 2, solve: day24_2::solve },
+    Solution { name: "day25_1", day: 25, part: 1, solve: day25_1::solve },
+];
+
+// Find default solver for a day/part
+pub fn find(day: u32, part: u32) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.day == day && s.part == part)
+}
+
+// Find solver by name, eg. "day12_2a"
+pub fn find_by_name(name: &str) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.name == name)
+}
@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+// Error from parsing puzzle input
+//
+// Line and column are 1 based, with 0 meaning not known. Parsers for a single line fill
+// in the column, and the caller that split the input into lines fills in the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            file: None,
+            line: 0,
+            column: 0,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            ..Self::new(message)
+        }
+    }
+
+    // Set line, if not already known
+    pub fn with_line(mut self, line: usize) -> Self {
+        if self.line == 0 {
+            self.line = line;
+        }
+        self
+    }
+
+    // Set column, if not already known
+    pub fn with_column(mut self, column: usize) -> Self {
+        if self.column == 0 {
+            self.column = column;
+        }
+        self
+    }
+
+    // Make column relative to 'line', rather than 'part' - part must be a slice of line
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        let start = column_of(line, part);
+        if start > 0 {
+            self.column = start + self.column.max(1) - 1;
+        }
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // file:line:column: message - leaving out parts that are not known
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+        }
+        if self.line > 0 {
+            write!(f, "{}:", self.line)?;
+            if self.column > 0 {
+                write!(f, "{}:", self.column)?;
+            }
+        }
+        if self.file.is_some() || self.line > 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+// 1 based column of 'part' within 'line', or 0 if part is not a slice of line
+pub fn column_of(line: &str, part: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let p = part.as_ptr() as usize;
+    if p >= start && p + part.len() <= start + line.len() {
+        line[..p - start].chars().count() + 1
+    } else {
+        0
+    }
+}
+
+// Parse a number, or other FromStr value
+pub fn parse_value<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| ParseError::new(format!("Bad value: {s:?}")))
+}
+
+// Parse each line of input, adding line numbers to any error
+pub fn parse_lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| f(l).map_err(|e| e.with_line(i + 1)))
+        .collect()
+}
+
+#[test]
+fn test_display() {
+    let e = ParseError::new("Bad var: 'q'");
+    assert_eq!(e.to_string(), "Bad var: 'q'");
+    let e = e.with_column(3).with_line(12);
+    assert_eq!(e.to_string(), "12:3: Bad var: 'q'");
+    assert_eq!(e.in_file("inputs/day19").to_string(), "inputs/day19:12:3: Bad var: 'q'");
+}
+
+#[test]
+fn test_lines() {
+    let line = "R 6 (#70c710)";
+    assert_eq!(column_of(line, &line[2..3]), 3);
+    assert_eq!(column_of(line, "6"), 0);
+
+    let e = ParseError::new("Bad digit").with_column(2).within(line, &line[5..12]);
+    assert_eq!(e.column, 7);
+    let e = ParseError::new("Bad number").within(line, &line[2..3]);
+    assert_eq!(e.column, 3);
+
+    let r = parse_lines("1\n2\nx\n", parse_value::<u32>);
+    assert_eq!(r, Err(ParseError::at(3, 0, "Bad value: \"x\"")));
+    assert_eq!(parse_lines("1\n2\n", parse_value::<u32>), Ok(vec![1, 2]));
+}
@@ -0,0 +1,180 @@
+// Graphs written out for Graphviz (DOT) or Mermaid to draw
+use std::fmt::Write;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Dot,
+    Mermaid,
+}
+
+impl FromStr for Format {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dot" => Ok(Format::Dot),
+            "mermaid" => Ok(Format::Mermaid),
+            _ => Err(ParseError::new(format!("Unknown graph format: {s} - expected dot or mermaid"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Box,
+    Diamond,
+    Ellipse,
+    Circle,
+}
+
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub name: String,
+    pub shape: Shape,
+}
+
+#[derive(Debug, Clone)]
+pub struct Edge {
+    pub from: usize,
+    pub to: usize,
+    pub label: Option<String>,
+    // Drawn thick and red
+    pub highlight: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Graph {
+    pub directed: bool,
+    pub nodes: Vec<Node>,
+    pub edges: Vec<Edge>,
+}
+
+impl Graph {
+    pub fn new(directed: bool) -> Self {
+        Graph { directed, nodes: Vec::new(), edges: Vec::new() }
+    }
+
+    pub fn add_node(&mut self, name: &str, shape: Shape) -> usize {
+        self.nodes.push(Node { name: name.to_string(), shape });
+        self.nodes.len() - 1
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, label: Option<String>, highlight: bool) {
+        self.edges.push(Edge { from, to, label, highlight });
+    }
+
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Dot => self.to_dot(),
+            Format::Mermaid => self.to_mermaid(),
+        }
+    }
+
+    pub fn to_dot(&self) -> String {
+        let (kind, arrow) = if self.directed { ("digraph", "->") } else { ("graph", "--") };
+        let mut s = format!("{kind} {{\n");
+        for n in &self.nodes {
+            let shape = match n.shape {
+                Shape::Box => "box",
+                Shape::Diamond => "diamond",
+                Shape::Ellipse => "ellipse",
+                Shape::Circle => "circle",
+            };
+            writeln!(s, "  {} [shape={shape}];", dot_id(&n.name)).unwrap();
+        }
+        for e in &self.edges {
+            let mut attributes = Vec::new();
+            if let Some(label) = &e.label {
+                attributes.push(format!("label={}", dot_id(label)));
+            }
+            if e.highlight {
+                attributes.push("color=red, penwidth=3".to_string());
+            }
+            let attributes = if attributes.is_empty() { String::new() } else { format!(" [{}]", attributes.join(", ")) };
+            let (from, to) = (dot_id(&self.nodes[e.from].name), dot_id(&self.nodes[e.to].name));
+            writeln!(s, "  {from} {arrow} {to}{attributes};").unwrap();
+        }
+        s.push_str("}\n");
+        s
+    }
+
+    // Nodes are n0, n1 ... so that names can't clash with Mermaid keywords
+    pub fn to_mermaid(&self) -> String {
+        let mut s = String::from("flowchart LR\n");
+        for (i, n) in self.nodes.iter().enumerate() {
+            let name = mermaid_text(&n.name);
+            let node = match n.shape {
+                Shape::Box => format!("[{name}]"),
+                Shape::Diamond => format!("{{{name}}}"),
+                Shape::Ellipse => format!("([{name}])"),
+                Shape::Circle => format!("(({name}))"),
+            };
+            writeln!(s, "  n{i}{node}").unwrap();
+        }
+        let arrow = if self.directed { "-->" } else { "---" };
+        for e in &self.edges {
+            let label = e.label.as_ref().map_or(String::new(), |l| format!("|{}|", mermaid_text(l)));
+            writeln!(s, "  n{} {arrow}{label} n{}", e.from, e.to).unwrap();
+        }
+        // Links are styled by their position in the list
+        for (i, _) in self.edges.iter().enumerate().filter(|(_, e)| e.highlight) {
+            writeln!(s, "  linkStyle {i} stroke:red,stroke-width:3px").unwrap();
+        }
+        s
+    }
+}
+
+fn dot_id(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn mermaid_text(s: &str) -> String {
+    format!("\"{}\"", s.replace('"', "#quot;"))
+}
+
+#[test]
+fn test_export() {
+    let mut graph = Graph::new(true);
+    let a = graph.add_node("a", Shape::Box);
+    let b = graph.add_node("end", Shape::Diamond);
+    let c = graph.add_node("say \"c\"", Shape::Circle);
+    graph.add_edge(a, b, Some("0".to_string()), false);
+    graph.add_edge(b, c, None, true);
+
+    assert_eq!(
+        graph.render(Format::Dot),
+        [
+            "digraph {",
+            "  \"a\" [shape=box];",
+            "  \"end\" [shape=diamond];",
+            "  \"say \\\"c\\\"\" [shape=circle];",
+            "  \"a\" -> \"end\" [label=\"0\"];",
+            "  \"end\" -> \"say \\\"c\\\"\" [color=red, penwidth=3];",
+            "}\n",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        graph.render(Format::Mermaid),
+        [
+            "flowchart LR",
+            "  n0[\"a\"]",
+            "  n1{\"end\"}",
+            "  n2((\"say #quot;c#quot;\"))",
+            "  n0 -->|\"0\"| n1",
+            "  n1 --> n2",
+            "  linkStyle 1 stroke:red,stroke-width:3px\n",
+        ]
+        .join("\n")
+    );
+
+    graph.directed = false;
+    assert!(graph.to_dot().starts_with("graph {"));
+    assert!(graph.to_dot().contains("\"a\" -- \"end\""));
+    assert!(graph.to_mermaid().contains("n1 --- n2"));
+    assert_eq!("mermaid".parse::<Format>(), Ok(Format::Mermaid));
+    assert!("svg".parse::<Format>().is_err());
+}
@@ -0,0 +1,248 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::ParseError;
+
+// A grid cell that can be parsed from, and shown as, a single character
+pub trait Cell: Copy {
+    fn from_char(c: char) -> Option<Self>;
+    fn to_char(&self) -> char;
+}
+
+// '#' is set, anything else is clear
+impl Cell for bool {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c == '#')
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+// Single hex digit
+impl Cell for u8 {
+    fn from_char(c: char) -> Option<Self> {
+        c.to_digit(16).map(|d| d as u8)
+    }
+
+    fn to_char(&self) -> char {
+        char::from_digit(*self as u32 % 16, 16).unwrap()
+    }
+}
+
+// Offsets to 4-connected neighbours - E, S, W, N
+pub const NEIGHBOURS4: [(i32, i32); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+
+// Offsets to 8-connected neighbours - clockwise from E
+pub const NEIGHBOURS8: [(i32, i32); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+
+// Rectangular grid of cells, stored row by row
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<T>,
+}
+
+impl<T: Copy> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && x < self.width as i32 && y < self.height as i32
+    }
+
+    // Index into cells, or None if outside grid
+    pub fn offset(&self, x: i32, y: i32) -> Option<usize> {
+        if self.contains(x, y) {
+            Some(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Option<T> {
+        self.offset(x, y).map(|o| self.cells[o])
+    }
+
+    // Cell at x,y, or 'outside' if off the grid
+    pub fn get_or(&self, x: i32, y: i32, outside: T) -> T {
+        self.get(x, y).unwrap_or(outside)
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        self.offset(x, y).map(|o| &mut self.cells[o])
+    }
+
+    // Set cell at x,y - ignored if off the grid
+    pub fn set(&mut self, x: i32, y: i32, value: T) {
+        if let Some(o) = self.offset(x, y) {
+            self.cells[o] = value;
+        }
+    }
+
+    // Cell at x,y with grid repeated infinitely in all directions - grid must not be empty
+    pub fn get_wrapped(&self, x: i32, y: i32) -> T {
+        let wx = x.rem_euclid(self.width as i32);
+        let wy = y.rem_euclid(self.height as i32);
+        self.cells[wy as usize * self.width + wx as usize]
+    }
+
+    // New grid made of repeat_x by repeat_y copies of this one
+    pub fn tiled(&self, repeat_x: usize, repeat_y: usize) -> Self {
+        let width = self.width * repeat_x;
+        let height = self.height * repeat_y;
+        let mut cells = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(self.get_wrapped(x as i32, y as i32));
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    // Position of first cell, in row order, that matches predicate
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<(i32, i32)> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|o| ((o % self.width) as i32, (o / self.width) as i32))
+    }
+
+    // 4-connected neighbours of x,y that are on the grid
+    pub fn neighbours4(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        NEIGHBOURS4
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(nx, ny)| self.contains(nx, ny))
+    }
+
+    // 8-connected neighbours of x,y that are on the grid
+    pub fn neighbours8(&self, x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> + '_ {
+        NEIGHBOURS8
+            .iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+            .filter(|&(nx, ny)| self.contains(nx, ny))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = T> + '_ {
+        self.cells.iter().skip(x).step_by(self.width).copied()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // An empty grid has no rows, whatever its width
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        (0..self.width).map(|x| self.column(x).collect())
+    }
+}
+
+impl<T: Cell> Grid<T> {
+    // One row per line - all lines must be the same length, and there must be at least one cell
+    pub fn from_text(text: &str) -> Result<Self, ParseError> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, l) in text.lines().enumerate() {
+            if y == 0 {
+                width = l.chars().count();
+            }
+            for (x, c) in l.chars().enumerate() {
+                let cell = T::from_char(c)
+                    .ok_or_else(|| ParseError::at(y + 1, x + 1, format!("Bad character {c:?}")))?;
+                cells.push(cell);
+            }
+            if l.chars().count() != width {
+                return Err(ParseError::at(y + 1, 0, format!("Line length is not {width}")));
+            }
+            height += 1;
+        }
+        if width == 0 {
+            return Err(ParseError::new("Empty grid"));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+}
+
+impl<T: Cell> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for r in self.rows() {
+            writeln!(f, "{}", r.iter().map(Cell::to_char).collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_access() {
+    let g = Grid::<u8>::from_text("123\n456\n").unwrap();
+    assert_eq!((g.width, g.height), (3, 2));
+    assert_eq!(g.get(0, 0), Some(1));
+    assert_eq!(g.get(2, 1), Some(6));
+    assert_eq!(g.get(3, 0), None);
+    assert_eq!(g.get(0, -1), None);
+    assert_eq!(g.get_or(-1, 0, 0), 0);
+    assert_eq!(g.get_wrapped(-1, 0), 3);
+    assert_eq!(g.get_wrapped(4, 3), 5);
+    assert_eq!(g.position(|&c| c == 5), Some((1, 1)));
+    assert_eq!(g.row(1), &[4, 5, 6]);
+    assert_eq!(g.column(1).collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(g.columns().count(), 3);
+    assert_eq!(g.to_string(), "123\n456\n");
+    assert_eq!(g.tiled(2, 2).to_string(), "123123\n456456\n123123\n456456\n");
+}
+
+#[test]
+fn test_parse_errors() {
+    let e = Grid::<u8>::from_text("123\n4x6\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 2));
+    let e = Grid::<u8>::from_text("123\n45\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 0));
+    assert_eq!(Grid::<u8>::from_text("").unwrap_err().message, "Empty grid");
+    assert_eq!(Grid::<u8>::from_text("\n\n").unwrap_err().message, "Empty grid");
+    assert_eq!(Grid::new(0, 3, 0u8).rows().count(), 0);
+}
+
+#[test]
+fn test_neighbours() {
+    let g = Grid::new(3, 3, false);
+    assert_eq!(g.neighbours4(1, 1).count(), 4);
+    assert_eq!(g.neighbours4(0, 0).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+    assert_eq!(g.neighbours8(1, 1).count(), 8);
+    assert_eq!(g.neighbours8(2, 2).count(), 3);
+}
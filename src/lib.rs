@@ -0,0 +1,81 @@
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+pub mod bench;
+pub mod check;
+pub mod circuit;
+pub mod cycle;
+pub mod days;
+pub mod error;
+pub mod graph_export;
+pub mod grid;
+pub mod search;
+
+pub use error::ParseError;
+
+// Result of solving one part of a day's puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Unsolved,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+// Number, or "unsolved" - as written by Display
+impl FromStr for Answer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unsolved" => Ok(Answer::Unsolved),
+            _ => error::parse_value(s).map(Answer::Number),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Number(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Number(n as i64)
+    }
+}
+
+impl<T> From<Option<T>> for Answer
+where
+    T: Into<Answer>,
+{
+    fn from(n: Option<T>) -> Self {
+        n.map_or(Answer::Unsolved, Into::into)
+    }
+}
@@ -1,3 +1,474 @@
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, process, thread};
+
+use aoc_2023::bench::{self, Stats};
+use aoc_2023::check::{self, Expected};
+use aoc_2023::circuit::{Circuit, PulseFilter, Simulator, Trace, TraceFormat};
+use aoc_2023::days::{self, day14, day16, day19, day25_1, Solution};
+use aoc_2023::graph_export::Format;
+
+const USAGE: &str = "Usage: aoc --day <n> --part <n> [--input <path|->]
+       aoc --solution <name> [--input <path|->]
+       aoc --check [--day <n>] [--part <n>] [--solution <name>] [--input <path>]
+       aoc --bench [--day <n>] [--part <n>] [--solution <name>] [--input <path|->]
+                   [--iterations <n>] [--json <path>]
+       aoc --tilt <commands> [--load <edge>] [--input <path|->]
+       aoc [--animate] [--frames <dir>] [--input <path|->]
+       aoc --graph <dot|mermaid> --day <20|25> [--input <path|->]
+       aoc [--trace <filter>] [--break <filter>] [--trace-format <csv|json>] [--presses <n>]
+           [--input <path|->]
+       aoc --validate [--input <path|->]
+       aoc --explain <part> [--input <path|->]
+
+  --day <n>          Puzzle day (1-25)
+  --part <n>         Puzzle part (1 or 2)
+  --solution <name>  Solver by name, eg. day12_2a
+  --input <path|->   Input file, or - for stdin (default inputs/day<n>)
+  --check            Compare against recorded answers - other options pick which ones
+  --manifest <path>  Recorded answers for --check (default answers.txt)
+  --bench            Time parse and solve phases - other options pick which solutions
+  --iterations <n>   Runs of each solution for --bench (default 10)
+  --json <path>      Also write --bench report as JSON
+  --tilt <commands>  Tilt day 14 platform, eg. N, EE, NWSE*1000000000
+  --load <edge>      Edge to measure load on after --tilt - N, W, S or E (default N)
+  --animate          Play day 16 beams spreading out, a step at a time, in the terminal
+  --frames <dir>     Write each step of day 16 beams as a PPM image
+  --graph <format>   Print day 20 circuit, or day 25 components with the cut edges highlighted,
+                     for Graphviz (dot) or Mermaid (mermaid)
+  --trace <filter>   Press the day 20 button, printing pulses that match filter, eg.
+                     dest=bq,level=high or src=broadcaster - empty matches every pulse
+  --break <filter>   Stop pressing at the first pulse that matches filter
+  --trace-format <f> Print --trace as csv or json lines (default csv)
+  --presses <n>      Most button presses for --trace or --break (default 1000)
+  --validate         Check day 19 workflows for unknown, unused and looping workflows, rules
+                     that never match, and workflows that never accept
+  --explain <part>   Show the day 19 workflows a part goes through, and whether it is accepted,
+                     eg. '{x=787,m=2655,a=1222,s=2876}'";
+
+const DEFAULT_ITERATIONS: usize = 10;
+const FRAME_DELAY: Duration = Duration::from_millis(40);
+const FRAME_SCALE: usize = 4;
+const DEFAULT_PRESSES: usize = 1000;
+
+#[derive(Debug, Default)]
+struct Args {
+    day: Option<u32>,
+    part: Option<u32>,
+    solution: Option<String>,
+    input: Option<String>,
+    check: bool,
+    manifest: Option<String>,
+    bench: bool,
+    iterations: Option<u32>,
+    json: Option<String>,
+    tilt: Option<String>,
+    load: Option<day14::Direction>,
+    animate: bool,
+    frames: Option<String>,
+    graph: Option<Format>,
+    trace: Option<String>,
+    break_on: Option<String>,
+    trace_format: Option<TraceFormat>,
+    presses: Option<u32>,
+    validate: bool,
+    explain: Option<String>,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut r = Args::default();
+
+        while let Some(a) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("Missing value for {name}"));
+            match a.as_str() {
+                "--day" => r.day = Some(parse_number(&value("--day")?)?),
+                "--part" => r.part = Some(parse_number(&value("--part")?)?),
+                "--solution" => r.solution = Some(value("--solution")?),
+                "--input" => r.input = Some(value("--input")?),
+                "--check" => r.check = true,
+                "--manifest" => r.manifest = Some(value("--manifest")?),
+                "--bench" => r.bench = true,
+                "--iterations" => r.iterations = Some(parse_number(&value("--iterations")?)?),
+                "--json" => r.json = Some(value("--json")?),
+                "--tilt" => r.tilt = Some(value("--tilt")?),
+                "--load" => r.load = Some(parse_edge(&value("--load")?)?),
+                "--animate" => r.animate = true,
+                "--frames" => r.frames = Some(value("--frames")?),
+                "--graph" => r.graph = Some(value("--graph")?.parse().map_err(|e| format!("{e}"))?),
+                "--trace" => r.trace = Some(value("--trace")?),
+                "--break" => r.break_on = Some(value("--break")?),
+                "--trace-format" => {
+                    r.trace_format = Some(value("--trace-format")?.parse().map_err(|e| format!("{e}"))?)
+                }
+                "--presses" => r.presses = Some(parse_number(&value("--presses")?)?),
+                "--validate" => r.validate = true,
+                "--explain" => r.explain = Some(value("--explain")?),
+                "--help" | "-h" => return Err(String::new()),
+                _ => return Err(format!("Unknown argument: {a}")),
+            }
+        }
+
+        Ok(r)
+    }
+
+    fn solution(&self) -> Result<&'static Solution, String> {
+        match (&self.solution, self.day, self.part) {
+            (Some(name), _, _) => {
+                days::find_by_name(name).ok_or(format!("No solution named {name}"))
+            }
+            (None, Some(day), Some(part)) => {
+                days::find(day, part).ok_or(format!("No solution for day {day} part {part}"))
+            }
+            _ => Err("Need --day and --part, or --solution".to_string()),
+        }
+    }
+
+    // Does recorded answer match all the given options?
+    fn selects(&self, e: &Expected) -> bool {
+        self.day.is_none_or(|d| d == e.solution.day)
+            && self.part.is_none_or(|p| p == e.solution.part)
+            && self.solution.as_ref().is_none_or(|s| *s == e.solution.name)
+            && self.input.as_ref().is_none_or(|i| *i == e.input)
+    }
+
+    // Solutions matching all the given options, or every solution if none given
+    fn solutions(&self) -> Vec<&'static Solution> {
+        days::SOLUTIONS
+            .iter()
+            .filter(|s| {
+                self.day.is_none_or(|d| d == s.day)
+                    && self.part.is_none_or(|p| p == s.part)
+                    && self.solution.as_ref().is_none_or(|n| *n == s.name)
+            })
+            .collect()
+    }
+}
+
+fn parse_number(s: &str) -> Result<u32, String> {
+    s.parse::<u32>().map_err(|_| format!("Bad number: {s}"))
+}
+
+fn parse_edge(s: &str) -> Result<day14::Direction, String> {
+    let mut chars = s.chars();
+    match (chars.next().and_then(day14::Direction::from_char), chars.next()) {
+        (Some(d), None) => Ok(d),
+        _ => Err(format!("Bad edge: {s}")),
+    }
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|e| format!("Cannot read stdin: {e}"))?;
+        Ok(input)
+    } else {
+        fs::read_to_string(path).map_err(|e| format!("Cannot read {path}: {e}"))
+    }
+}
+
+fn run(args: &Args, solution: &Solution) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| solution.input_path());
+    let input = read_input(&path)?;
+
+    println!("Day {} part {} ({}) input: {}", solution.day, solution.part, solution.name, path);
+    let answer = (solution.solve)(&input).map_err(|e| e.in_file(&path).to_string())?;
+    println!("Answer: {answer}");
+
+    Ok(())
+}
+
+fn run_tilt(args: &Args, commands: &str) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| "inputs/day14".to_string());
+    let input = read_input(&path)?;
+    let edge = args.load.unwrap_or(day14::Direction::North);
+
+    println!("Day 14 tilts {commands:?} load on {edge:?} input: {path}");
+    let answer = day14::solve_commands(&input, commands, edge).map_err(|e| {
+        // Errors in commands have no line number
+        if e.line == 0 {
+            format!("--tilt: {e}")
+        } else {
+            e.in_file(&path).to_string()
+        }
+    })?;
+    println!("Answer: {answer}");
+
+    Ok(())
+}
+
+// Beam from part 1, step by step
+fn run_beams(args: &Args) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| "inputs/day16".to_string());
+    let input = read_input(&path)?;
+    let mut grid = day16::Grid::create(&input).map_err(|e| e.in_file(&path).to_string())?;
+    let steps = grid.trace_steps(day16::Beam { x: -1, y: 0, dx: 1, dy: 0 });
+
+    if let Some(dir) = &args.frames {
+        fs::create_dir_all(dir).map_err(|e| format!("Cannot create {dir}: {e}"))?;
+    }
+    if args.animate {
+        // Clear screen
+        print!("\x1b[2J");
+    }
+
+    let mut result = Ok(());
+    let mut frame = 0;
+    grid.replay(&steps, |g, lit| {
+        if args.animate {
+            print!("{}", g.render_ansi(lit));
+            let _ = io::stdout().flush();
+            thread::sleep(FRAME_DELAY);
+        }
+        if let (Some(dir), Ok(())) = (&args.frames, &result) {
+            let file = Path::new(dir).join(format!("beam_{frame:04}.ppm"));
+            result = fs::write(&file, g.render_ppm(lit, FRAME_SCALE))
+                .map_err(|e| format!("Cannot write {}: {e}", file.display()));
+        }
+        frame += 1;
+    });
+    result?;
+
+    println!("Day 16 beams input: {path}");
+    println!("Energised: {} after {} steps", grid.energised(), steps.len());
+
+    Ok(())
+}
+
+fn run_graph(args: &Args, format: Format) -> Result<(), String> {
+    let day = args.day.ok_or("Need --day for --graph")?;
+    let path = args.input.clone().unwrap_or_else(|| format!("inputs/day{day}"));
+    let input = read_input(&path)?;
+
+    let graph = match day {
+        20 => Circuit::from_text(&input).map(|c| c.graph()),
+        25 => day25_1::graph(&input),
+        _ => return Err(format!("No graph for day {day}")),
+    }
+    .map_err(|e| e.in_file(&path).to_string())?;
+    print!("{}", graph.render(format));
+
+    Ok(())
+}
+
+// Press day 20 button, recording pulses that match --trace, until one matches --break
+fn run_trace(args: &Args) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| "inputs/day20".to_string());
+    let input = read_input(&path)?;
+    let circuit = Circuit::from_text(&input).map_err(|e| e.in_file(&path).to_string())?;
+    let filter = |option: &str, text: &str| PulseFilter::parse(&circuit, text).map_err(|e| format!("{option}: {e}"));
+
+    let trace = match &args.trace {
+        Some(text) => Some(RefCell::new(Trace::new(filter("--trace", text)?))),
+        None => None,
+    };
+    let mut sim = Simulator::new(&circuit);
+    if let Some(trace) = &trace {
+        sim.subscribe(|press, pulse| trace.borrow_mut().record(press, pulse));
+    }
+    if let Some(text) = &args.break_on {
+        sim.break_on(filter("--break", text)?);
+    }
+
+    let presses = args.presses.map_or(DEFAULT_PRESSES, |p| p as usize);
+    let stopped = sim.press_until_break(presses);
+    drop(sim);
+
+    if let Some(trace) = trace {
+        print!("{}", trace.into_inner().render(&circuit, args.trace_format.unwrap_or(TraceFormat::Csv)));
+    }
+    match stopped {
+        Some((press, p)) => {
+            eprintln!("Break at press {press}: {} -{}-> {}", circuit.name(p.src), p.level, circuit.name(p.dest))
+        }
+        None if args.break_on.is_some() => eprintln!("No break in {presses} presses"),
+        None => (),
+    }
+
+    Ok(())
+}
+
+fn run_validate(args: &Args) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| "inputs/day19".to_string());
+    let input = read_input(&path)?;
+
+    let found = day19::validate(&input).map_err(|e| e.in_file(&path).to_string())?;
+    for e in &found {
+        println!("{}", e.clone().in_file(&path));
+    }
+    let s = if found.len() == 1 { "" } else { "s" };
+    println!("{} problem{s} found in {path}", found.len());
+
+    Ok(())
+}
+
+fn run_explain(args: &Args, part: &str) -> Result<(), String> {
+    let path = args.input.clone().unwrap_or_else(|| "inputs/day19".to_string());
+    let input = read_input(&path)?;
+
+    let (process, _) = day19::parse(&input).map_err(|e| e.in_file(&path).to_string())?;
+    let part = day19::Part::from_string(part, process.schema()).map_err(|e| format!("--explain: {e}"))?;
+    let explanation = process.explain(&part);
+    println!("Day 19 workflows input: {path}");
+    println!("{explanation}");
+    if explanation.accepted {
+        println!("Accepted - rating {}", part.sum());
+    } else {
+        println!("Rejected");
+    }
+
+    Ok(())
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+fn run_check(args: &Args) -> Result<(), String> {
+    let manifest = args.manifest.as_deref().unwrap_or(check::MANIFEST_PATH);
+    let entries = check::read_manifest(manifest)?;
+
+    // Per day: passed, total, time
+    let mut days: BTreeMap<u32, (usize, usize, Duration)> = BTreeMap::new();
+
+    for e in entries.iter().filter(|e| args.selects(e)) {
+        let o = check::check(e);
+        let result = match (&o.actual, o.passed()) {
+            (Ok(a), true) => format!("{a}"),
+            (Ok(a), false) => format!("expected {}, got {a}", e.answer),
+            (Err(err), _) => format!("expected {}, got error: {err}", e.answer),
+        };
+        println!(
+            "{:4} {:9} {:22} {:10.3}ms  {}",
+            if o.passed() { "ok" } else { "FAIL" },
+            e.solution.name,
+            e.input,
+            millis(o.time),
+            result
+        );
+
+        let d = days.entry(e.solution.day).or_default();
+        d.0 += o.passed() as usize;
+        d.1 += 1;
+        d.2 += o.time;
+    }
+
+    println!();
+    for (day, (passed, total, time)) in &days {
+        let status = if passed == total { "ok" } else { "FAIL" };
+        println!("{status:4} Day {day:2}  {passed:2}/{total:<2} {:10.3}ms", millis(*time));
+    }
+
+    let passed = days.values().map(|d| d.0).sum::<usize>();
+    let total = days.values().map(|d| d.1).sum::<usize>();
+    let time = days.values().map(|d| d.2).sum::<Duration>();
+    println!("{passed}/{total} passed in {:.3}ms", millis(time));
+
+    if passed == total {
+        Ok(())
+    } else {
+        Err(format!("{} failed", total - passed))
+    }
+}
+
+// min/median/max in ms
+fn stats(s: Option<Stats>) -> String {
+    match s {
+        Some(s) => format!(
+            "{:9.3} {:9.3} {:9.3}",
+            millis(s.min),
+            millis(s.median),
+            millis(s.max)
+        ),
+        None => format!("{:>9} {:>9} {:>9}", "-", "-", "-"),
+    }
+}
+
+fn run_bench(args: &Args) -> Result<(), String> {
+    let solutions = args.solutions();
+    if solutions.is_empty() {
+        return Err("No solutions selected".to_string());
+    }
+    let iterations = args.iterations.map_or(DEFAULT_ITERATIONS, |n| n as usize);
+
+    // Stdin can only be read once
+    let stdin = match args.input.as_deref() {
+        Some("-") => Some(read_input("-")?),
+        _ => None,
+    };
+
+    println!("Times in ms over {iterations} iterations");
+    println!(
+        "{:32} {:>9} {:>9} {:>9}   {:>9} {:>9} {:>9}   answer",
+        "", "parse min", "median", "max", "solve min", "median", "max"
+    );
+
+    let mut reports = Vec::new();
+    for solution in solutions {
+        let path = args.input.clone().unwrap_or_else(|| solution.input_path());
+        let input = match &stdin {
+            Some(input) => input.clone(),
+            None => read_input(&path)?,
+        };
+        let report = bench::bench(solution, &path, &input, iterations).map_err(|e| e.to_string())?;
+        println!(
+            "{:9} {:22} {}   {}   {}",
+            solution.name,
+            path,
+            stats(report.parse),
+            stats(Some(report.solve)),
+            report.answer
+        );
+        reports.push(report);
+    }
+
+    if let Some(path) = &args.json {
+        fs::write(path, bench::to_json(&reports)).map_err(|e| format!("Cannot write {path}: {e}"))?;
+    }
+
+    Ok(())
+}
+
+fn usage(e: &str) -> ! {
+    if !e.is_empty() {
+        eprintln!("{e}");
+    }
+    eprintln!("{USAGE}");
+    process::exit(1);
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args = Args::parse(env::args().skip(1)).unwrap_or_else(|e| usage(&e));
+
+    // Errors from run or check are not usage problems
+    let result = if args.check {
+        run_check(&args)
+    } else if args.bench {
+        run_bench(&args)
+    } else if let Some(commands) = &args.tilt {
+        run_tilt(&args, commands)
+    } else if args.animate || args.frames.is_some() {
+        run_beams(&args)
+    } else if let Some(format) = args.graph {
+        run_graph(&args, format)
+    } else if args.trace.is_some() || args.break_on.is_some() {
+        run_trace(&args)
+    } else if args.validate {
+        run_validate(&args)
+    } else if let Some(part) = &args.explain {
+        run_explain(&args, part)
+    } else {
+        let solution = args.solution().unwrap_or_else(|e| usage(&e));
+        run(&args, solution)
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
 }
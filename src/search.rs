@@ -0,0 +1,169 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+// A state in a search, with the moves out of it
+//
+// 'C' is whatever the state needs to look at to find its moves - usually the puzzle's map.
+pub trait SearchState<C: ?Sized>: Sized {
+    // States reachable in one move, with the cost of each move
+    fn successors(&self, context: &C) -> Vec<(Self, usize)>;
+
+    fn is_goal(&self, context: &C) -> bool;
+
+    // Lower bound on the cost from here to a goal - A* needs this to never overestimate
+    fn heuristic(&self, _context: &C) -> usize {
+        0
+    }
+}
+
+// Route found by a search, from a start state to a goal
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+// Follow parents back from 'end' - first state is the one with no parent
+pub fn reconstruct<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, end: &S) -> Vec<S> {
+    let mut states = vec![end.clone()];
+    while let Some(p) = parents.get(states.last().unwrap()) {
+        states.push(p.clone());
+    }
+    states.reverse();
+    states
+}
+
+fn best_first<C, S>(context: &C, starts: impl IntoIterator<Item = S>, use_heuristic: bool) -> Option<Path<S>>
+where
+    C: ?Sized,
+    S: SearchState<C> + Clone + Eq + Hash,
+{
+    let priority = |s: &S, cost: usize| if use_heuristic { cost + s.heuristic(context) } else { cost };
+
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+
+    // Heap refers to states by index into 'pending', so states need not be Ord
+    let mut pending = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for s in starts {
+        heap.push(Reverse((priority(&s, 0), 0, pending.len())));
+        costs.insert(s.clone(), 0);
+        pending.push(s);
+    }
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let state = pending[idx].clone();
+        if costs.get(&state).is_some_and(|&c| c < cost) {
+            // Already reached more cheaply
+            continue;
+        }
+        if state.is_goal(context) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&parents, &state),
+            });
+        }
+
+        for (next, step_cost) in state.successors(context) {
+            let next_cost = cost + step_cost;
+            if costs.get(&next).is_none_or(|&c| next_cost < c) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                heap.push(Reverse((priority(&next, next_cost), next_cost, pending.len())));
+                pending.push(next);
+            }
+        }
+    }
+
+    None
+}
+
+// Cheapest path from any start to a goal
+pub fn dijkstra<C, S>(context: &C, starts: impl IntoIterator<Item = S>) -> Option<Path<S>>
+where
+    C: ?Sized,
+    S: SearchState<C> + Clone + Eq + Hash,
+{
+    best_first(context, starts, false)
+}
+
+// Cheapest path, using the heuristic to search towards the goal first
+pub fn astar<C, S>(context: &C, starts: impl IntoIterator<Item = S>) -> Option<Path<S>>
+where
+    C: ?Sized,
+    S: SearchState<C> + Clone + Eq + Hash,
+{
+    best_first(context, starts, true)
+}
+
+// Path with fewest moves from any start to a goal - cost is still the sum of its moves
+pub fn bfs<C, S>(context: &C, starts: impl IntoIterator<Item = S>) -> Option<Path<S>>
+where
+    C: ?Sized,
+    S: SearchState<C> + Clone + Eq + Hash,
+{
+    let mut costs = HashMap::new();
+    let mut parents = HashMap::new();
+    let mut pending = VecDeque::new();
+    for s in starts {
+        costs.insert(s.clone(), 0);
+        pending.push_back(s);
+    }
+
+    while let Some(state) = pending.pop_front() {
+        let cost = costs[&state];
+        if state.is_goal(context) {
+            return Some(Path {
+                cost,
+                states: reconstruct(&parents, &state),
+            });
+        }
+
+        for (next, step_cost) in state.successors(context) {
+            if !costs.contains_key(&next) {
+                costs.insert(next.clone(), cost + step_cost);
+                parents.insert(next.clone(), state.clone());
+                pending.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+#[test]
+fn test_search() {
+    // Moves from each node to the listed nodes with costs
+    struct TestGraph {
+        edges: Vec<Vec<(usize, usize)>>,
+        goal: usize,
+    }
+
+    impl SearchState<TestGraph> for usize {
+        fn successors(&self, g: &TestGraph) -> Vec<(Self, usize)> {
+            g.edges[*self].clone()
+        }
+
+        fn is_goal(&self, g: &TestGraph) -> bool {
+            *self == g.goal
+        }
+    }
+
+    // 0 -> 1 -> 3 is cheapest, 0 -> 3 is fewest moves
+    let g = TestGraph {
+        edges: vec![vec![(1, 1), (2, 1), (3, 10)], vec![(3, 2)], vec![(1, 1), (3, 4)], vec![]],
+        goal: 3,
+    };
+
+    let p = dijkstra(&g, [0]).unwrap();
+    assert_eq!(p, Path { cost: 3, states: vec![0, 1, 3] });
+    assert_eq!(astar(&g, [0]), Some(p));
+    assert_eq!(bfs(&g, [0]), Some(Path { cost: 10, states: vec![0, 3] }));
+    assert_eq!(dijkstra(&g, [3]).unwrap().states, vec![3]);
+
+    let g = TestGraph { goal: 4, ..g };
+    assert_eq!(dijkstra(&g, [0]), None);
+    assert_eq!(bfs(&g, [0]), None);
+}